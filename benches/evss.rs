@@ -0,0 +1,139 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use ark_std::test_rng;
+
+use evss::biaccumulator381::*;
+
+const DEGREES: [usize; 4] = [16, 64, 256, 1024];
+
+fn setup(c: &mut Criterion) {
+    let mut group = c.benchmark_group("setup");
+    group.sample_size(10);
+    for &degree in &DEGREES {
+        group.bench_with_input(BenchmarkId::from_parameter(degree), &degree, |b, &degree| {
+            let rng = &mut test_rng();
+            b.iter(|| EVSS381::setup(degree, rng).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn commit(c: &mut Criterion) {
+    let mut group = c.benchmark_group("commit");
+    for &degree in &DEGREES {
+        let rng = &mut test_rng();
+        let params = EVSS381::setup(degree, rng).unwrap();
+        group.bench_with_input(BenchmarkId::from_parameter(degree), &params, |b, params| {
+            b.iter(|| EVSS381::commit(params, F381::rand(rng), rng).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn get_share(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get_share");
+    for &degree in &DEGREES {
+        let rng = &mut test_rng();
+        let params = EVSS381::setup(degree, rng).unwrap();
+        let poly = EVSS381::commit(&params, F381::rand(rng), rng).unwrap();
+        group.bench_with_input(BenchmarkId::from_parameter(degree), &poly, |b, poly| {
+            b.iter(|| EVSS381::get_share(F381::from(1u32), &params, poly, rng).unwrap())
+        });
+    }
+    group.finish();
+}
+
+/// All `degree + 1` shares, through one `PC::open` per point and through `get_shares_batch`.
+fn get_shares(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get_shares");
+    group.sample_size(10);
+    for &degree in &DEGREES[..3] {
+        let rng = &mut test_rng();
+        let params = EVSS381::setup(degree, rng).unwrap();
+        let poly = EVSS381::commit(&params, F381::rand(rng), rng).unwrap();
+        let points: Vec<F381> = (1..=degree as u32 + 1).map(F381::from).collect();
+        group.bench_with_input(BenchmarkId::new("open", degree), &poly, |b, poly| {
+            b.iter(|| EVSS381::get_shares(&points, &params, poly, rng).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("batch", degree), &poly, |b, poly| {
+            b.iter(|| EVSS381::get_shares_batch(&points, &params, poly, rng).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn check(c: &mut Criterion) {
+    let mut group = c.benchmark_group("check");
+    for &degree in &DEGREES {
+        let rng = &mut test_rng();
+        let params = EVSS381::setup(degree, rng).unwrap();
+        let public_params = params.get_public_params();
+        let poly = EVSS381::commit(&params, F381::rand(rng), rng).unwrap();
+        let commit = poly.get_commit();
+        let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng).unwrap();
+        group.bench_with_input(BenchmarkId::from_parameter(degree), &share, |b, share| {
+            b.iter(|| assert!(EVSS381::check(&public_params, &commit, share, rng).unwrap()))
+        });
+    }
+    group.finish();
+}
+
+/// Compares `check`, which prepares `h` and `beta_h` inside `KZG10::check`, against
+/// `check_prepared` with both prepared once up front. Public parameters are derived once for both.
+fn check_prepared(c: &mut Criterion) {
+    let mut group = c.benchmark_group("check_prepared");
+    for &degree in &DEGREES[2..] {
+        let rng = &mut test_rng();
+        let params = EVSS381::setup(degree, rng).unwrap();
+        let public_params = params.get_public_params();
+        let prepared = PreparedParams381::new(public_params.clone());
+        let poly = EVSS381::commit(&params, F381::rand(rng), rng).unwrap();
+        let commit = poly.get_commit();
+        let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng).unwrap();
+        group.bench_with_input(BenchmarkId::new("unprepared", degree), &share, |b, share| {
+            b.iter(|| assert!(EVSS381::check(&public_params, &commit, share, rng).unwrap()))
+        });
+        group.bench_with_input(BenchmarkId::new("prepared", degree), &share, |b, share| {
+            b.iter(|| assert!(EVSS381::check_prepared(&prepared, &commit, share).unwrap()))
+        });
+    }
+    group.finish();
+}
+
+fn check_batch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("check_batch");
+    for &degree in &DEGREES {
+        let rng = &mut test_rng();
+        let creds: Vec<F381> = (0..degree).map(|_| F381::rand(rng)).collect();
+        let params = Biaccumulator381::setup(degree, rng).unwrap();
+        let public_params = params.get_public_params();
+        let poly = Biaccumulator381::commit(&params, &creds, rng).unwrap();
+        let commit = poly.get_commit();
+        let subset = &creds[..degree / 4];
+        let witness = Biaccumulator381::create_batch_witness(subset, &params, &poly, rng).unwrap();
+        group.bench_with_input(BenchmarkId::from_parameter(degree), &witness, |b, witness| {
+            b.iter(|| {
+                assert!(Biaccumulator381::check_batch(subset, &public_params, &commit, witness, rng).unwrap())
+            })
+        });
+    }
+    group.finish();
+}
+
+fn reconstruct(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reconstruct");
+    for &degree in &DEGREES {
+        let rng = &mut test_rng();
+        let params = EVSS381::setup(degree, rng).unwrap();
+        let poly = EVSS381::commit(&params, F381::rand(rng), rng).unwrap();
+        let points: Vec<F381> = (1..degree + 2).map(|i| F381::from(i as u32)).collect();
+        let shares = EVSS381::get_shares(&points, &params, &poly, rng).unwrap();
+        group.bench_with_input(BenchmarkId::from_parameter(degree), &shares, |b, shares| {
+            b.iter(|| EVSS381::reconstruct(shares))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, setup, commit, get_share, get_shares, check, check_prepared, check_batch, reconstruct);
+criterion_main!(benches);
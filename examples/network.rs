@@ -0,0 +1,80 @@
+//! A dealer shares a secret with verifier threads over TCP. Each verifier receives the public
+//! parameters, the commitment and its share as bincode, checks the share and sends it back
+//! if it is valid; the dealer's side then reconstructs from the returned shares.
+//!
+//! `test_rng` keeps the run reproducible; use a cryptographic RNG such as `OsRng` in practice.
+
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+use ark_std::test_rng;
+use serde::{Deserialize, Serialize};
+
+use evss::evss381::*;
+
+const DEGREE: usize = 4;
+const VERIFIERS: u32 = 7;
+
+/// Everything a verifier needs, sent by the dealer in one message.
+#[derive(Serialize, Deserialize)]
+struct Dealt {
+    public_params: EVSSPublicParams381,
+    commit: EVSSCommit381,
+    share: EVSSShare381,
+}
+
+fn verifier(addr: std::net::SocketAddr) -> bincode::Result<()> {
+    let rng = &mut test_rng();
+    let stream = TcpStream::connect(addr).map_err(bincode::Error::from)?;
+    let dealt: Dealt = bincode::deserialize_from(&stream)?;
+    let valid = EVSS381::check(&dealt.public_params, &dealt.commit, &dealt.share, rng)
+        .map_err(|e| io::Error::other(e.to_string()))?;
+    let reply = if valid { Some(dealt.share) } else { None };
+    bincode::serialize_into(&stream, &reply)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    // arkworks errors do not implement `std::error::Error` without its `std` feature.
+    let params = EVSS381::setup(DEGREE, rng).map_err(|e| e.to_string())?;
+    let poly = EVSS381::commit(&params, secret, rng).map_err(|e| e.to_string())?;
+
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+    let verifiers: Vec<_> = (0..VERIFIERS)
+        .map(|_| thread::spawn(move || verifier(addr)))
+        .collect();
+
+    let mut streams = Vec::new();
+    for index in 1..=VERIFIERS {
+        let (stream, _) = listener.accept()?;
+        let dealt = Dealt {
+            public_params: params.get_public_params(),
+            commit: poly.get_commit(),
+            share: EVSS381::get_share(F381::from(index), &params, &poly, rng).map_err(|e| e.to_string())?,
+        };
+        bincode::serialize_into(&stream, &dealt)?;
+        streams.push(stream);
+    }
+
+    let mut shares = Vec::new();
+    for stream in &streams {
+        let reply: Option<EVSSShare381> = bincode::deserialize_from(stream)?;
+        shares.extend(reply);
+    }
+    for handle in verifiers {
+        handle.join().expect("verifier thread panicked")?;
+    }
+
+    let public_params = params.get_public_params();
+    println!("{} of {} shares verified", shares.len(), VERIFIERS);
+    if !EVSS381::can_reconstruct(&public_params, &shares) {
+        return Err("not enough valid shares to reconstruct".into());
+    }
+    let reconstructed = EVSS381::try_reconstruct(&public_params, &shares).map_err(|e| e.to_string())?;
+    assert_eq!(secret, reconstructed);
+    println!("reconstructed the secret from {} shares", shares.len());
+    Ok(())
+}
@@ -0,0 +1,12 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use evss::evss381::*;
+
+// Malformed input must come back as an `Err`, never as a panic or a runaway allocation.
+fuzz_target!(|data: &[u8]| {
+    let _ = bincode::deserialize::<EVSSShare381>(data);
+    let _ = bincode::deserialize::<EVSSCommit381>(data);
+    let _ = serde_json::from_slice::<EVSSShare381>(data);
+    let _ = serde_json::from_slice::<EVSSCommit381>(data);
+});
@@ -5,8 +5,56 @@ use ark_std::{marker::PhantomData, vec::Vec};
 use serde::de::{self, Deserializer, SeqAccess, Visitor};
 use serde::ser::{self, Serializer};
 
+use std::cell::Cell;
 use std::fmt;
 
+/// How many bytes a sequence-encoded value may contain unless `with_max_seq_len` says otherwise.
+pub const DEFAULT_MAX_SEQ_LEN: usize = 1 << 24;
+
+thread_local! {
+    static MAX_SEQ_LEN: Cell<usize> = const { Cell::new(DEFAULT_MAX_SEQ_LEN) };
+}
+
+// Restores the previous limit even if the deserialization closure panics.
+struct SeqLenGuard(usize);
+
+impl Drop for SeqLenGuard {
+    fn drop(&mut self) {
+        MAX_SEQ_LEN.with(|max| max.set(self.0));
+    }
+}
+
+/// Runs `f`, typically one deserialization call, with sequence-encoded values capped at `len`
+/// bytes. The limit only applies on the current thread and only until `f` returns. Only the
+/// byte-array fallback is affected; hex strings and byte buffers are already sized by the input.
+pub fn with_max_seq_len<T, G: FnOnce() -> T>(len: usize, f: G) -> T {
+    let _guard = SeqLenGuard(MAX_SEQ_LEN.with(|max| max.replace(len)));
+    f()
+}
+
+pub fn max_seq_len() -> usize {
+    MAX_SEQ_LEN.with(Cell::get)
+}
+
+fn read_seq<'de, A>(mut seq: A) -> Result<Vec<u8>, A::Error>
+where
+    A: SeqAccess<'de>,
+{
+    let max = max_seq_len();
+    let too_long = || de::Error::custom(format!("byte sequence longer than {}", max));
+    if seq.size_hint().is_some_and(|hint| hint > max) {
+        return Err(too_long());
+    }
+    let mut buf: Vec<u8> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+    while let Some(i) = seq.next_element()? {
+        if buf.len() == max {
+            return Err(too_long());
+        }
+        buf.push(i);
+    }
+    Ok(buf)
+}
+
 pub fn to_bytes<S, T>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -37,16 +85,11 @@ impl<'de, T: FromBytes> Visitor<'de> for BytesVisitor<T> {
             Err(e) => Err(E::custom(format!("{}", e))),
         }
     }
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        let mut buf: Vec<u8> = Vec::new();
-        let mut val = seq.next_element()?;
-        while let Some(i) = val {
-            buf.push(i);
-            val = seq.next_element()?;
-        }
+        let buf = read_seq(seq)?;
         match T::read(&buf[..]) {
             Ok(i) => Ok(i),
             Err(e) => Err(de::Error::custom(format!("{}", e))),
@@ -62,6 +105,9 @@ where
     deserializer.deserialize_bytes(BytesVisitor::<T> { _t: PhantomData })
 }
 
+/// Uses `CanonicalSerialize::serialize`, which is already the compressed encoding in
+/// arkworks 0.2 (curve points are written as `x` plus a sign flag). Human-readable formats
+/// get a hex string, binary formats get raw bytes.
 pub fn canonical_serialize<S, T>(data: &T, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -69,6 +115,7 @@ where
 {
     let mut buf: Vec<u8> = Vec::new();
     match data.serialize(&mut buf) {
+        Ok(_) if serializer.is_human_readable() => serializer.serialize_str(&hex::encode(&buf)),
         Ok(_) => serializer.serialize_bytes(&buf[..]),
         Err(e) => Err(ser::Error::custom(format!("{}", e))),
     }
@@ -81,7 +128,7 @@ struct CanonicalVisitor<T: CanonicalDeserialize> {
 impl<'de, T: CanonicalDeserialize> Visitor<'de> for CanonicalVisitor<T> {
     type Value = T;
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("a chunk of bytes")
+        formatter.write_str("a chunk of bytes or a hex string")
     }
     fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
     where
@@ -92,16 +139,20 @@ impl<'de, T: CanonicalDeserialize> Visitor<'de> for CanonicalVisitor<T> {
             Err(e) => Err(E::custom(format!("{}", e))),
         }
     }
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
     where
-        A: SeqAccess<'de>,
+        E: de::Error,
     {
-        let mut buf: Vec<u8> = Vec::new();
-        let mut val = seq.next_element()?;
-        while let Some(i) = val {
-            buf.push(i);
-            val = seq.next_element()?;
+        match hex::decode(v) {
+            Ok(buf) => self.visit_bytes(&buf[..]),
+            Err(e) => Err(E::custom(format!("{}", e))),
         }
+    }
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let buf = read_seq(seq)?;
         match T::deserialize(&buf[..]) {
             Ok(i) => Ok(i),
             Err(e) => Err(de::Error::custom(format!("{}", e))),
@@ -114,5 +165,10 @@ where
     D: Deserializer<'de>,
     T: CanonicalDeserialize,
 {
-    deserializer.deserialize_bytes(CanonicalVisitor::<T> { _t: PhantomData })
+    // Human-readable input may be a hex string or a legacy array of bytes.
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(CanonicalVisitor::<T> { _t: PhantomData })
+    } else {
+        deserializer.deserialize_bytes(CanonicalVisitor::<T> { _t: PhantomData })
+    }
 }
@@ -1,38 +1,96 @@
-use ark_ff::Field;
+use ark_ff::FftField;
 use ark_poly::UVPolynomial;
 use ark_poly::univariate::DensePolynomial;
 use ark_poly_commit::PolynomialCommitment;
-use ark_std::{iter::once, marker::PhantomData};
+use ark_serialize::CanonicalSerialize;
+use ark_std::{collections::BTreeSet, iter::once, marker::PhantomData, vec::Vec};
 
+use crate::ark_serde::{canonical_deserialize, canonical_serialize};
+use crate::error::EvssError;
 use crate::evss::*;
-use crate::helper::{label_polynomial, label_commit};
+use crate::helper::{
+    label_polynomial, label_commit, mul_by_linear, divide_by_linear, divide_polynomial, fs_hash,
+    vanishing_polynomial, DEFAULT_LABEL,
+};
 
 use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
 
-pub struct Biaccumulator<F: Field, PC: PolynomialCommitment<F, DensePolynomial<F>>> {
+const BATCH_DOMAIN: &[u8] = b"rust-evss/biaccumulator-batch/v1";
+const QUOTIENT_LABEL: &str = "quotient";
+
+/// Proof that the accumulator polynomial `f` vanishes on a whole subset `S`: a commitment to
+/// `q = f / Z_S` together with one opening of `f` and `q` at a Fiat-Shamir point `r`, checked
+/// against `f(r) = Z_S(r) * q(r)`.
+#[derive(Serialize, Deserialize)]
+pub struct BatchWitness<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>> {
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub quotient_commit: PC::Commitment,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub value: F,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub quotient_value: F,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub proof: PC::Proof,
+}
+
+impl<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>> std::fmt::Debug for BatchWitness<F, PC> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchWitness")
+         .field("value", &self.value)
+         .field("quotient_value", &self.quotient_value)
+         .finish()
+    }
+
+}
+
+impl<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>> Clone for BatchWitness<F, PC> {
+
+    fn clone(&self) -> Self {
+        BatchWitness {
+            quotient_commit: self.quotient_commit.clone(),
+            value: self.value,
+            quotient_value: self.quotient_value,
+            proof: self.proof.clone(),
+        }
+    }
+
+}
+
+pub struct Biaccumulator<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>> {
     _f: PhantomData<F>,
     _pc: PhantomData<PC>,
 }
 
-impl<F: Field, PC: PolynomialCommitment<F, DensePolynomial<F>>> Biaccumulator<F, PC> {
+impl<F: FftField, PC: PolynomialCommitment<F, DensePolynomial<F>>> Biaccumulator<F, PC> {
 
     pub fn setup<R: RngCore>(
         degree: usize,
         rng: &mut R,
-    ) -> Result<EVSSParams<F, DensePolynomial<F>, PC>, PC::Error> {
+    ) -> Result<EVSSParams<F, DensePolynomial<F>, PC>, EvssError<PC::Error>> {
         EVSS::<F, DensePolynomial<F>, PC>::setup(degree, rng)
     }
 
+    /// Accumulates the distinct elements of `cred`; duplicates are dropped so every element is a
+    /// simple root. Fails with `DegreeExceeded` if more than `pp.degree` distinct elements remain.
     pub fn commit<R: RngCore>(
         pp: &EVSSParams<F, DensePolynomial<F>, PC>,
         cred: &[F],
         rng: &mut R,
-    ) -> Result<EVSSPolynomial<F, DensePolynomial<F>, PC>, PC::Error> {
-        let mut p = DensePolynomial::<F>::from_coefficients_slice(&[F::from(1 as u32)]);
-        for &c in cred {
-            p = p.naive_mul(&DensePolynomial::<F>::from_coefficients_slice(&[-c, F::from(1 as u32)]));
+    ) -> Result<EVSSPolynomial<F, DensePolynomial<F>, PC>, EvssError<PC::Error>> {
+        let cred: Vec<F> = cred.iter().copied().collect::<BTreeSet<F>>().into_iter().collect();
+        if cred.len() > pp.degree {
+            return Err(EvssError::DegreeExceeded {
+                got: cred.len(),
+                max: pp.degree,
+            });
         }
-        let poly = label_polynomial(&p);
+        let poly = label_polynomial(DEFAULT_LABEL, &vanishing_polynomial(&cred));
         let (lc, r) = PC::commit(&pp.committer_key, once(&poly), Some(rng))?;
         Ok(EVSSPolynomial {
             polynomial: poly.polynomial().clone(),
@@ -41,33 +99,180 @@ impl<F: Field, PC: PolynomialCommitment<F, DensePolynomial<F>>> Biaccumulator<F,
         })
     }
 
+    /// Multiplies in `(X - new_cred)` and re-commits; witnesses issued before must be re-issued.
+    pub fn add_element<R: RngCore>(
+        params: &EVSSParams<F, DensePolynomial<F>, PC>,
+        poly: &EVSSPolynomial<F, DensePolynomial<F>, PC>,
+        new_cred: F,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, DensePolynomial<F>, PC>, EvssError<PC::Error>> {
+        let coeffs = mul_by_linear(&poly.polynomial.coeffs, new_cred);
+        EVSS::commit_polynomial(params, &DensePolynomial::from_coefficients_vec(coeffs), rng)
+    }
+
+    /// Divides out `(X - cred)` and re-commits, failing with `NotAMember` if `cred` is not a root.
+    pub fn remove_element<R: RngCore>(
+        params: &EVSSParams<F, DensePolynomial<F>, PC>,
+        poly: &EVSSPolynomial<F, DensePolynomial<F>, PC>,
+        cred: F,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, DensePolynomial<F>, PC>, EvssError<PC::Error>> {
+        let (quotient, remainder) = divide_by_linear(&poly.polynomial.coeffs, cred);
+        if remainder != F::zero() {
+            return Err(EvssError::NotAMember);
+        }
+        EVSS::commit_polynomial(params, &DensePolynomial::from_coefficients_vec(quotient), rng)
+    }
+
     pub fn create_witness<R: RngCore>(
         cred: F,
         params: &EVSSParams<F, DensePolynomial<F>, PC>,
         poly: &EVSSPolynomial<F, DensePolynomial<F>, PC>,
         rng: &mut R,
-    ) -> Result<EVSSShare<F, DensePolynomial<F>, PC>, PC::Error> {
+    ) -> Result<EVSSShare<F, DensePolynomial<F>, PC>, EvssError<PC::Error>> {
         EVSS::get_share(cred, params, poly, rng)
     }
 
+    #[must_use = "the boolean result indicates whether verification succeeded"]
     pub fn check<R: RngCore>(
         params: &EVSSPublicParams<F, DensePolynomial<F>, PC>,
         commit: &EVSSCommit<F,DensePolynomial<F>, PC>,
         share: &EVSSShare<F, DensePolynomial<F>, PC>,
         rng: &mut R,
-    ) -> Result<bool, PC::Error> {
-        if share.value != F::from(0 as u32) {
-            return Ok(false); 
+    ) -> Result<bool, EvssError<PC::Error>> {
+        Ok(Self::check_detailed(params, commit, share, rng)? == CheckOutcome::Valid)
+    }
+
+    /// Always verifies the opening first, so `ProofInvalid` means a forgery while `NotAMember`
+    /// means a genuine opening of a nonzero value.
+    #[must_use = "the outcome indicates whether verification succeeded"]
+    pub fn check_detailed<R: RngCore>(
+        params: &EVSSPublicParams<F, DensePolynomial<F>, PC>,
+        commit: &EVSSCommit<F, DensePolynomial<F>, PC>,
+        share: &EVSSShare<F, DensePolynomial<F>, PC>,
+        rng: &mut R,
+    ) -> Result<CheckOutcome, EvssError<PC::Error>> {
+        match EVSS::check_detailed(params, commit, share, rng)? {
+            CheckOutcome::Valid if share.value != F::zero() => Ok(CheckOutcome::NotAMember),
+            outcome => Ok(outcome),
+        }
+    }
+
+    /// Whether every witness proves membership, stopping at the first that does not.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn verify_all<R: RngCore>(
+        params: &EVSSPublicParams<F, DensePolynomial<F>, PC>,
+        commit: &EVSSCommit<F, DensePolynomial<F>, PC>,
+        witnesses: &[EVSSShare<F, DensePolynomial<F>, PC>],
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        for witness in witnesses {
+            if !Self::check(params, commit, witness, rng)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    pub fn create_nonmembership_witness<R: RngCore>(
+        elem: F,
+        params: &EVSSParams<F, DensePolynomial<F>, PC>,
+        poly: &EVSSPolynomial<F, DensePolynomial<F>, PC>,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, DensePolynomial<F>, PC>, EvssError<PC::Error>> {
+        EVSS::get_share(elem, params, poly, rng)
+    }
+
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_nonmembership<R: RngCore>(
+        params: &EVSSPublicParams<F, DensePolynomial<F>, PC>,
+        commit: &EVSSCommit<F, DensePolynomial<F>, PC>,
+        share: &EVSSShare<F, DensePolynomial<F>, PC>,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        if share.value == F::zero() {
+            return Ok(false);
+        }
+        EVSS::check(params, commit, share, rng)
+    }
+
+    pub fn create_batch_witness<R: RngCore>(
+        creds: &[F],
+        params: &EVSSParams<F, DensePolynomial<F>, PC>,
+        poly: &EVSSPolynomial<F, DensePolynomial<F>, PC>,
+        rng: &mut R,
+    ) -> Result<BatchWitness<F, PC>, EvssError<PC::Error>> {
+        let (coeffs, remainder) = divide_polynomial(&poly.polynomial.coeffs, &vanishing_polynomial(creds).coeffs);
+        if remainder.iter().any(|c| !c.is_zero()) {
+            return Err(EvssError::NotAMember);
+        }
+        let quotient = label_polynomial(QUOTIENT_LABEL, &DensePolynomial::from_coefficients_vec(coeffs));
+        let (qc, qr) = PC::commit(&params.committer_key, once(&quotient), Some(rng))?;
+        let quotient_commit = qc[0].commitment().clone();
+        let point = Self::batch_point(creds, &poly.commit, &quotient_commit);
+        let value = poly.evaluate(point);
+        let quotient_value = quotient.evaluate(&point);
+        let proof = PC::open(
+            &params.committer_key,
+            vec![&label_polynomial(DEFAULT_LABEL, &poly.polynomial), &quotient],
+            vec![
+                &label_commit::<F, DensePolynomial<F>, PC>(DEFAULT_LABEL, &poly.commit),
+                &label_commit::<F, DensePolynomial<F>, PC>(QUOTIENT_LABEL, &quotient_commit),
+            ],
+            &point,
+            Self::batch_challenge(point, value, quotient_value),
+            vec![&poly.rands, &qr[0]],
+            Some(rng),
+        )?;
+        Ok(BatchWitness {
+            quotient_commit,
+            value,
+            quotient_value,
+            proof,
+        })
+    }
+
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_batch<R: RngCore>(
+        creds: &[F],
+        params: &EVSSPublicParams<F, DensePolynomial<F>, PC>,
+        commit: &EVSSCommit<F, DensePolynomial<F>, PC>,
+        witness: &BatchWitness<F, PC>,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        let point = Self::batch_point(creds, &commit.commit, &witness.quotient_commit);
+        let vanishing: F = creds.iter().map(|&c| point - c).product();
+        if witness.value != vanishing * witness.quotient_value {
+            return Ok(false);
         }
-        PC::check(
+        Ok(PC::check(
             &params.verifier_key,
-            once(&label_commit::<F, DensePolynomial<F>, PC>(&commit.commit)),
-            &share.point,
-            once(share.value),
-            &share.proof,
-            share.challenge,
+            vec![
+                &label_commit::<F, DensePolynomial<F>, PC>(DEFAULT_LABEL, &commit.commit),
+                &label_commit::<F, DensePolynomial<F>, PC>(QUOTIENT_LABEL, &witness.quotient_commit),
+            ],
+            &point,
+            vec![witness.value, witness.quotient_value],
+            &witness.proof,
+            Self::batch_challenge(point, witness.value, witness.quotient_value),
             Some(rng),
-        )
+        )?)
+    }
+
+    fn batch_point(creds: &[F], commit: &PC::Commitment, quotient_commit: &PC::Commitment) -> F {
+        let mut buf: Vec<u8> = Vec::new();
+        commit.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        quotient_commit.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        creds.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        fs_hash(BATCH_DOMAIN, &buf)
+    }
+
+    fn batch_challenge(point: F, value: F, quotient_value: F) -> F {
+        let mut buf: Vec<u8> = Vec::new();
+        (point, value, quotient_value)
+            .serialize(&mut buf)
+            .expect("serializing into a vector cannot fail");
+        fs_hash(BATCH_DOMAIN, &buf)
     }
 
 }
@@ -2,18 +2,23 @@ use crate::*;
 pub use crate::evss381::*;
 
 pub type Biaccumulator381 = biaccumulator::Biaccumulator<F381, PC381>;
+pub type BatchWitness381 = biaccumulator::BatchWitness<F381, PC381>;
 
 #[cfg(test)]
 mod tests {
 
     use crate::biaccumulator381::*;
+    use crate::error::EvssError;
+    use crate::helper::vanishing_polynomial;
 
+    use ark_ff::Zero;
+    use ark_poly::{Polynomial, UVPolynomial};
     use ark_std::test_rng;
 
     const DEGREE: usize = 10;
 
     #[test]
-    fn test_functionality() -> Result<(), <PC381 as PolynomialCommitment<F381, Poly381>>::Error> {
+    fn test_functionality() -> Result<(), EvssError381> {
         let rng = &mut test_rng();
         let vec: Vec<F381> = (0..DEGREE).map(|_| F381::rand(rng)).collect();
         let params = Biaccumulator381::setup(DEGREE, rng)?;
@@ -25,4 +30,154 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_verify_all() -> Result<(), EvssError381> {
+        let rng = &mut test_rng();
+        let vec: Vec<F381> = (0..DEGREE).map(|_| F381::rand(rng)).collect();
+        let params = Biaccumulator381::setup(DEGREE, rng)?;
+        let public_params = params.get_public_params();
+        let poly = Biaccumulator381::commit(&params, &vec[..], rng)?;
+        let mut witnesses = Vec::new();
+        for cred in &vec[..3] {
+            witnesses.push(Biaccumulator381::create_witness(*cred, &params, &poly, rng)?);
+        }
+        assert!(Biaccumulator381::verify_all(&public_params, &poly.get_commit(), &witnesses, rng)?);
+        witnesses.push(Biaccumulator381::create_witness(F381::rand(rng), &params, &poly, rng)?);
+        assert!(!Biaccumulator381::verify_all(&public_params, &poly.get_commit(), &witnesses, rng)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_matches_naive_product() -> Result<(), EvssError381> {
+        let rng = &mut test_rng();
+        let vec: Vec<F381> = (0..37).map(|_| F381::rand(rng)).collect();
+        let params = Biaccumulator381::setup(vec.len(), rng)?;
+        let poly = Biaccumulator381::commit(&params, &vec[..], rng)?;
+        let mut naive = Poly381::from_coefficients_slice(&[F381::from(1u32)]);
+        for &c in &vec {
+            naive = naive.naive_mul(&Poly381::from_coefficients_slice(&[-c, F381::from(1u32)]));
+        }
+        assert_eq!(naive, poly.polynomial);
+        Ok(())
+    }
+
+    #[test]
+    fn test_vanishing_polynomial() -> Result<(), EvssError381> {
+        let rng = &mut test_rng();
+        let vec: Vec<F381> = (0..DEGREE).map(|_| F381::rand(rng)).collect();
+        let params = Biaccumulator381::setup(DEGREE, rng)?;
+        let poly = Biaccumulator381::commit(&params, &vec[..], rng)?;
+        let vanishing = vanishing_polynomial(&vec);
+        assert_eq!(poly.polynomial, vanishing);
+        assert!(vec.iter().all(|c| vanishing.evaluate(c).is_zero()));
+        assert_eq!(Poly381::from_coefficients_slice(&[F381::from(1u32)]), vanishing_polynomial(&[]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_nonmembership() -> Result<(), EvssError381> {
+        let rng = &mut test_rng();
+        let vec: Vec<F381> = (0..DEGREE).map(|_| F381::rand(rng)).collect();
+        let params = Biaccumulator381::setup(DEGREE, rng)?;
+        let public_params = params.get_public_params();
+        let poly = Biaccumulator381::commit(&params, &vec[..], rng)?;
+        let outsider = F381::rand(rng);
+        let witness = Biaccumulator381::create_nonmembership_witness(outsider, &params, &poly, rng)?;
+        assert!(Biaccumulator381::check_nonmembership(&public_params, &poly.get_commit(), &witness, rng)?);
+        assert!(!Biaccumulator381::check(&public_params, &poly.get_commit(), &witness, rng)?);
+        assert_eq!(
+            CheckOutcome::NotAMember,
+            Biaccumulator381::check_detailed(&public_params, &poly.get_commit(), &witness, rng)?
+        );
+        let mut forged = witness.clone();
+        forged.value += F381::from(1u32);
+        assert_eq!(
+            CheckOutcome::ProofInvalid,
+            Biaccumulator381::check_detailed(&public_params, &poly.get_commit(), &forged, rng)?
+        );
+        forged.value = F381::from(0u32);
+        assert_eq!(
+            CheckOutcome::ProofInvalid,
+            Biaccumulator381::check_detailed(&public_params, &poly.get_commit(), &forged, rng)?
+        );
+        let witness = Biaccumulator381::create_nonmembership_witness(vec[0], &params, &poly, rng)?;
+        assert!(!Biaccumulator381::check_nonmembership(&public_params, &poly.get_commit(), &witness, rng)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_element() -> Result<(), EvssError381> {
+        let rng = &mut test_rng();
+        let vec: Vec<F381> = (0..DEGREE).map(|_| F381::rand(rng)).collect();
+        let params = Biaccumulator381::setup(DEGREE, rng)?;
+        let mut poly = Biaccumulator381::commit(&params, &vec[..DEGREE - 1], rng)?;
+        poly = Biaccumulator381::add_element(&params, &poly, vec[DEGREE - 1], rng)?;
+        assert_eq!(poly.polynomial, Biaccumulator381::commit(&params, &vec[..], rng)?.polynomial);
+        for cred in &vec {
+            let witness = Biaccumulator381::create_witness(*cred, &params, &poly, rng)?;
+            assert!(Biaccumulator381::check(&params.get_public_params(), &poly.get_commit(), &witness, rng)?);
+        }
+        assert!(matches!(
+            Biaccumulator381::add_element(&params, &poly, F381::rand(rng), rng),
+            Err(EvssError::DegreeExceeded { got, max }) if got == DEGREE + 1 && max == DEGREE
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_element() -> Result<(), EvssError381> {
+        let rng = &mut test_rng();
+        let vec: Vec<F381> = (0..DEGREE).map(|_| F381::rand(rng)).collect();
+        let params = Biaccumulator381::setup(DEGREE, rng)?;
+        let mut poly = Biaccumulator381::commit(&params, &vec[..], rng)?;
+        poly = Biaccumulator381::remove_element(&params, &poly, vec[0], rng)?;
+        assert_eq!(poly.polynomial, Biaccumulator381::commit(&params, &vec[1..], rng)?.polynomial);
+        let witness = Biaccumulator381::create_witness(vec[0], &params, &poly, rng)?;
+        assert!(!Biaccumulator381::check(&params.get_public_params(), &poly.get_commit(), &witness, rng)?);
+        assert!(matches!(
+            Biaccumulator381::remove_element(&params, &poly, vec[0], rng),
+            Err(EvssError::NotAMember)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_witness() -> Result<(), EvssError381> {
+        let rng = &mut test_rng();
+        let vec: Vec<F381> = (0..DEGREE).map(|_| F381::rand(rng)).collect();
+        let params = Biaccumulator381::setup(DEGREE, rng)?;
+        let public_params = params.get_public_params();
+        let poly = Biaccumulator381::commit(&params, &vec[..], rng)?;
+        let subset = &vec[2..7];
+        let witness = Biaccumulator381::create_batch_witness(subset, &params, &poly, rng)?;
+        assert!(Biaccumulator381::check_batch(subset, &public_params, &poly.get_commit(), &witness, rng)?);
+        assert!(!Biaccumulator381::check_batch(&vec[2..8], &public_params, &poly.get_commit(), &witness, rng)?);
+        let mut forged = witness.clone();
+        forged.value += F381::from(1u32);
+        assert!(!Biaccumulator381::check_batch(subset, &public_params, &poly.get_commit(), &forged, rng)?);
+        let outsider = [vec[0], F381::rand(rng)];
+        assert!(matches!(
+            Biaccumulator381::create_batch_witness(&outsider, &params, &poly, rng),
+            Err(EvssError::NotAMember)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_dedup_and_bound() -> Result<(), EvssError381> {
+        let rng = &mut test_rng();
+        let vec: Vec<F381> = (0..DEGREE).map(|_| F381::rand(rng)).collect();
+        let params = Biaccumulator381::setup(DEGREE, rng)?;
+        let mut doubled = vec.clone();
+        doubled.extend_from_slice(&vec);
+        let poly = Biaccumulator381::commit(&params, &doubled, rng)?;
+        assert_eq!(poly.polynomial, Biaccumulator381::commit(&params, &vec, rng)?.polynomial);
+        doubled.push(F381::rand(rng));
+        assert!(matches!(
+            Biaccumulator381::commit(&params, &doubled, rng),
+            Err(EvssError::DegreeExceeded { got, max }) if got == DEGREE + 1 && max == DEGREE
+        ));
+        Ok(())
+    }
+
 }
@@ -0,0 +1,53 @@
+use ark_ff::Field;
+use ark_poly::UVPolynomial;
+use ark_poly_commit::PolynomialCommitment;
+use ark_std::vec::Vec;
+
+use crate::error::EvssError;
+use crate::evss::*;
+
+use rand_core::{RngCore, SeedableRng};
+
+/// Owns the parameters and the RNG of a dealing session, so the whole transcript can be
+/// reproduced from the seed passed to `new_from_seed`.
+pub struct Dealer<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>, R: RngCore + SeedableRng> {
+    params: EVSSParams<F, P, PC>,
+    rng: R,
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>, R: RngCore + SeedableRng> Dealer<F, P, PC, R> {
+
+    pub fn new(params: EVSSParams<F, P, PC>, rng: R) -> Self {
+        Dealer { params, rng }
+    }
+
+    pub fn new_from_seed(params: EVSSParams<F, P, PC>, seed: R::Seed) -> Self {
+        Self::new(params, R::from_seed(seed))
+    }
+
+    pub fn params(&self) -> &EVSSParams<F, P, PC> {
+        &self.params
+    }
+
+    pub fn commit(&mut self, secret: F) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        EVSS::commit(&self.params, secret, &mut self.rng)
+    }
+
+    pub fn get_share(
+        &mut self,
+        point: F,
+        poly: &EVSSPolynomial<F, P, PC>,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        EVSS::get_share(point, &self.params, poly, &mut self.rng)
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn get_shares(
+        &mut self,
+        points: &[F],
+        poly: &EVSSPolynomial<F, P, PC>,
+    ) -> Result<Vec<EVSSShare<F, P, PC>>, EvssError<PC::Error>> {
+        EVSS::get_shares(points, &self.params, poly, &mut self.rng)
+    }
+
+}
@@ -0,0 +1,69 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum EvssError<E> {
+    Commitment(E),
+    DegreeBounded,
+    DegreeIsZero,
+    DegreeExceeded { got: usize, max: usize },
+    DuplicatePoint,
+    HidingBoundExceeded { got: usize, max: usize },
+    InsufficientShares { have: usize, need: usize },
+    InvalidPadding,
+    LengthMismatch { expected: usize, got: usize },
+    NotAMember,
+    PointMismatch,
+    TooManyErrors,
+    UnknownPoint,
+    ZeroPoint,
+}
+
+impl<E> From<E> for EvssError<E> {
+
+    fn from(e: E) -> Self {
+        EvssError::Commitment(e)
+    }
+
+}
+
+impl<E: fmt::Display> fmt::Display for EvssError<E> {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvssError::Commitment(e) => write!(f, "commitment scheme error: {}", e),
+            EvssError::DegreeBounded => write!(f, "commitment has a degree bound"),
+            EvssError::DegreeIsZero => write!(f, "degree must be at least 1"),
+            EvssError::DegreeExceeded { got, max } => {
+                write!(f, "degree {} exceeds the supported maximum {}", got, max)
+            }
+            EvssError::DuplicatePoint => write!(f, "duplicate evaluation point"),
+            EvssError::HidingBoundExceeded { got, max } => {
+                write!(f, "hiding bound {} exceeds the trimmed maximum {}", got, max)
+            }
+            EvssError::InsufficientShares { have, need } => {
+                write!(f, "insufficient shares: have {}, need {}", have, need)
+            }
+            EvssError::InvalidPadding => write!(f, "reconstructed chunks are not validly padded"),
+            EvssError::LengthMismatch { expected, got } => {
+                write!(f, "length mismatch: expected {}, got {}", expected, got)
+            }
+            EvssError::NotAMember => write!(f, "element is not in the accumulated set"),
+            EvssError::PointMismatch => write!(f, "shares are for different evaluation points"),
+            EvssError::TooManyErrors => write!(f, "too many faulty shares to correct"),
+            EvssError::UnknownPoint => write!(f, "evaluation point was not expected"),
+            EvssError::ZeroPoint => write!(f, "evaluation point 0 would reveal the secret"),
+        }
+    }
+
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for EvssError<E> {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EvssError::Commitment(e) => Some(e),
+            _ => None,
+        }
+    }
+
+}
@@ -1,17 +1,53 @@
-use ark_ff::Field;
-use ark_poly::UVPolynomial;
-use ark_poly_commit::PolynomialCommitment;
-use ark_std::{iter::once, marker::PhantomData, vec::Vec};
+use ark_ff::{BigInteger, FftField, Field, One, PrimeField};
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain, UVPolynomial};
+use ark_poly_commit::{
+    Evaluations, LabeledCommitment, PCCommitment, PCCommitterKey, PCUniversalParams, PCVerifierKey, PolynomialCommitment,
+    QuerySet,
+};
+use ark_serialize::CanonicalSerialize;
+use ark_std::{cfg_iter, convert::TryFrom, collections::BTreeSet, iter::once, marker::PhantomData, vec::Vec};
 
-use rand_core::RngCore;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
 
 use crate::ark_serde::{canonical_deserialize, canonical_serialize};
-use crate::helper::{label_polynomial, label_commit};
+use crate::error::EvssError;
+use crate::homomorphic::HomomorphicPC;
+use crate::transcript::{Blake2bTranscript, EvssTranscript};
+use crate::helper::{
+    label_polynomial, label_polynomial_bounded, label_polynomial_hiding, label_commit, label_commit_bounded, DEFAULT_LABEL, fingerprint,
+    lagrange_coefficients, lagrange_coefficients_at, interpolate_polynomial, mul_by_linear, fs_hash,
+    divide_polynomial, divide_by_linear, multipoint_evaluate, to_bigints, validate_points, FixedBaseMsm, solve_linear_system, hash_to_field, hash_to_bytes,
+};
 use serde::{Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
+
+const KNOWLEDGE_DOMAIN: &[u8] = b"rust-evss/knowledge/v1";
+const SECRET_DOMAIN: &[u8] = b"rust-evss/secret-bytes/v1";
+const SHARE_ID_DOMAIN: &[u8] = b"rust-evss/share-id/v1";
+
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_poly::univariate::DensePolynomial;
+use ark_poly_commit::kzg10::{self, KZG10};
+use ark_poly_commit::marlin_pc::MarlinKZG10;
 
+/// The dealer's parameters. **Never publish these:** verifiers only need
+/// `get_public_params()`, and the committer key has no business leaving the dealer.
 #[derive(Serialize, Deserialize)]
+#[serde(try_from = "RawParams<F, P, PC>", bound(deserialize = ""))]
 pub struct EVSSParams<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
     pub degree: usize,
+    /// The largest hiding bound the committer key was trimmed for, 0 unless set up with
+    /// `EVSS::setup_with_hiding_bound`.
+    pub hiding_bound: usize,
     #[serde(serialize_with = "canonical_serialize")]
     #[serde(deserialize_with = "canonical_deserialize")]
     pub committer_key: PC::CommitterKey,
@@ -20,8 +56,81 @@ pub struct EVSSParams<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F,
     pub verifier_key: PC::VerifierKey,
 }
 
+// Deserialization goes through these so that `validate` runs on every decoded value.
+#[derive(Deserialize)]
+#[serde(rename = "EVSSParams")]
+struct RawParams<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
+    degree: usize,
+    hiding_bound: usize,
+    #[serde(deserialize_with = "canonical_deserialize")]
+    committer_key: PC::CommitterKey,
+    #[serde(deserialize_with = "canonical_deserialize")]
+    verifier_key: PC::VerifierKey,
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> TryFrom<RawParams<F, P, PC>> for EVSSParams<F, P, PC> {
+    type Error = EvssError<PC::Error>;
+
+    fn try_from(raw: RawParams<F, P, PC>) -> Result<Self, Self::Error> {
+        let mut params = EVSSParams::new(raw.degree, raw.committer_key, raw.verifier_key)?;
+        params.hiding_bound = raw.hiding_bound;
+        Ok(params)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "EVSSPublicParams", deny_unknown_fields)]
+struct RawPublicParams<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
+    degree: usize,
+    #[serde(deserialize_with = "canonical_deserialize")]
+    verifier_key: PC::VerifierKey,
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> TryFrom<RawPublicParams<F, P, PC>> for EVSSPublicParams<F, P, PC> {
+    type Error = EvssError<PC::Error>;
+
+    fn try_from(raw: RawPublicParams<F, P, PC>) -> Result<Self, Self::Error> {
+        EVSSPublicParams::new(raw.degree, raw.verifier_key)
+    }
+}
+
 impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSSParams<F, P, PC> {
 
+    /// Wraps keys from an external ceremony, checking that they support `degree`. The hiding
+    /// bound starts at 0; set `hiding_bound` if the keys were trimmed for more.
+    pub fn new(
+        degree: usize,
+        committer_key: PC::CommitterKey,
+        verifier_key: PC::VerifierKey,
+    ) -> Result<Self, EvssError<PC::Error>> {
+        let params = EVSSParams {
+            degree,
+            hiding_bound: 0,
+            committer_key,
+            verifier_key,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// Checks that both keys support `degree`, which deserialization does automatically.
+    pub fn validate(&self) -> Result<(), EvssError<PC::Error>> {
+        let supported = self
+            .committer_key
+            .supported_degree()
+            .min(self.verifier_key.supported_degree());
+        if self.degree == 0 {
+            return Err(EvssError::DegreeIsZero);
+        }
+        if self.degree > supported {
+            return Err(EvssError::DegreeExceeded {
+                got: self.degree,
+                max: supported,
+            });
+        }
+        Ok(())
+    }
+
     pub fn get_public_params(&self) -> EVSSPublicParams<F, P, PC> {
         EVSSPublicParams {
             degree: self.degree,
@@ -29,6 +138,59 @@ impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSSParams<F,
         }
     }
 
+    /// Separates the publishable half from the committer key, which only the dealer keeps.
+    pub fn split(self) -> (EVSSPublicParams<F, P, PC>, CommitterKeyBlob<F, P, PC>) {
+        let blob = CommitterKeyBlob {
+            hiding_bound: self.hiding_bound,
+            committer_key: self.committer_key,
+            _f: PhantomData,
+            _p: PhantomData,
+        };
+        let public_params = EVSSPublicParams {
+            degree: self.degree,
+            verifier_key: self.verifier_key,
+        };
+        (public_params, blob)
+    }
+
+    /// Reverses `split`, validating that the committer key supports the public degree.
+    pub fn join(
+        public_params: EVSSPublicParams<F, P, PC>,
+        blob: CommitterKeyBlob<F, P, PC>,
+    ) -> Result<Self, EvssError<PC::Error>> {
+        let mut params = EVSSParams::new(public_params.degree, blob.committer_key, public_params.verifier_key)?;
+        params.hiding_bound = blob.hiding_bound;
+        Ok(params)
+    }
+
+    /// Canonical byte size of the contents, counting `degree` and `hiding_bound` as `u64`s and
+    /// excluding any framing added by the serde format.
+    pub fn serialized_size(&self) -> usize {
+        16 + self.committer_key.serialized_size() + self.verifier_key.serialized_size()
+    }
+
+    /// Writes the parameters as JSON, so one setup can be distributed to every party.
+    pub fn save<Q: AsRef<Path>>(&self, path: Q) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()
+    }
+
+    pub fn load<Q: AsRef<Path>>(path: Q) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+
+}
+
+impl<F: FftField, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSSParams<F, P, PC> {
+
+    /// The radix-2 domain of size `degree.next_power_of_two()`, e.g. the share points for
+    /// `EVSS::get_shares_fft`. `None` if the field has no subgroup that large.
+    pub fn evaluation_domain(&self) -> Option<Radix2EvaluationDomain<F>> {
+        Radix2EvaluationDomain::new(self.degree.next_power_of_two())
+    }
+
 }
 
 impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Debug for EVSSParams<F, P, PC> {
@@ -36,6 +198,7 @@ impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Deb
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("EVSSParams")
          .field("degree", &self.degree)
+         .field("hiding_bound", &self.hiding_bound)
          .field("committer_key", &self.committer_key)
          .field("verifier_key", &self.verifier_key)
          .finish()
@@ -48,6 +211,7 @@ impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for EVS
     fn clone(&self) -> Self {
         EVSSParams {
             degree: self.degree,
+            hiding_bound: self.hiding_bound,
             committer_key: self.committer_key.clone(),
             verifier_key: self.verifier_key.clone(),
         }
@@ -55,7 +219,59 @@ impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for EVS
 
 }
 
+/// The committer key half of `EVSSParams::split`. Like `EVSSParams` it must never be
+/// published; store it encrypted at rest and recombine with `EVSSParams::join`.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CommitterKeyBlob<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
+    hiding_bound: usize,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    committer_key: PC::CommitterKey,
+    #[serde(skip)]
+    _f: PhantomData<F>,
+    #[serde(skip)]
+    _p: PhantomData<P>,
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Debug for CommitterKeyBlob<F, P, PC> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommitterKeyBlob")
+         .field("hiding_bound", &self.hiding_bound)
+         .field("committer_key", &self.committer_key)
+         .finish()
+    }
+
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for CommitterKeyBlob<F, P, PC> {
+
+    fn clone(&self) -> Self {
+        CommitterKeyBlob {
+            hiding_bound: self.hiding_bound,
+            committer_key: self.committer_key.clone(),
+            _f: PhantomData,
+            _p: PhantomData,
+        }
+    }
+
+}
+
+/// Rejects serialized `EVSSParams` on deserialization, so full parameters published by
+/// mistake are not silently accepted; use `from_params_bytes` to extract them on purpose.
+///
+/// Public parameters carry no committer key, so dealing with them does not compile:
+///
+/// ```compile_fail
+/// use evss::evss381::*;
+/// let rng = &mut ark_std::test_rng();
+/// let params = EVSS381::setup(4, rng).unwrap();
+/// let public_params = EVSSPublicParams381::from(&params);
+/// let _poly = EVSS381::commit(&public_params, F381::from(1u32), rng);
+/// ```
 #[derive(Serialize, Deserialize)]
+#[serde(try_from = "RawPublicParams<F, P, PC>", bound(deserialize = ""))]
 pub struct EVSSPublicParams<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
     pub degree: usize,
     #[serde(serialize_with = "canonical_serialize")]
@@ -63,6 +279,54 @@ pub struct EVSSPublicParams<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitme
     pub verifier_key: PC::VerifierKey,
 }
 
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSSPublicParams<F, P, PC> {
+
+    pub fn new(degree: usize, verifier_key: PC::VerifierKey) -> Result<Self, EvssError<PC::Error>> {
+        let params = EVSSPublicParams {
+            degree,
+            verifier_key,
+        };
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// Number of shares with distinct points that `try_reconstruct` requires.
+    pub fn threshold(&self) -> usize {
+        self.degree + 1
+    }
+
+    /// Checks that the verifier key supports `degree`, which deserialization does automatically.
+    pub fn validate(&self) -> Result<(), EvssError<PC::Error>> {
+        if self.degree == 0 {
+            return Err(EvssError::DegreeIsZero);
+        }
+        if self.degree > self.verifier_key.supported_degree() {
+            return Err(EvssError::DegreeExceeded {
+                got: self.degree,
+                max: self.verifier_key.supported_degree(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn serialized_size(&self) -> usize {
+        8 + self.verifier_key.serialized_size()
+    }
+
+    /// Extracts the public parameters from JSON-serialized `EVSSParams`, as written by
+    /// `EVSSParams::save`, dropping the committer key and hiding bound before anything is
+    /// deserialized.
+    pub fn from_params_bytes(bytes: &[u8]) -> serde_json::Result<Self> {
+        let mut value: serde_json::Value = serde_json::from_slice(bytes)?;
+        if let Some(fields) = value.as_object_mut() {
+            fields.remove("committer_key");
+            fields.remove("hiding_bound");
+        }
+        serde_json::from_value(value)
+    }
+
+}
+
 impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Debug for EVSSPublicParams<F, P, PC> {
 
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -85,6 +349,52 @@ impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for EVS
 
 }
 
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> From<&EVSSParams<F, P, PC>> for EVSSPublicParams<F, P, PC> {
+
+    fn from(params: &EVSSParams<F, P, PC>) -> Self {
+        params.get_public_params()
+    }
+
+}
+
+/// KZG verifier parameters with `h` and `beta_h` prepared for pairings once, for verifying
+/// many shares with `EVSS::check_prepared`. Committing has nothing to prepare: arkworks 0.2
+/// commits with a plain MSM over the committer key.
+#[allow(clippy::type_complexity)]
+pub struct PreparedParams<E: PairingEngine> {
+    pub public_params: EVSSPublicParams<E::Fr, DensePolynomial<E::Fr>, MarlinKZG10<E, DensePolynomial<E::Fr>>>,
+    prepared_h: E::G2Prepared,
+    prepared_beta_h: E::G2Prepared,
+}
+
+impl<E: PairingEngine> PreparedParams<E> {
+
+    #[allow(clippy::type_complexity)]
+    pub fn new(
+        public_params: EVSSPublicParams<E::Fr, DensePolynomial<E::Fr>, MarlinKZG10<E, DensePolynomial<E::Fr>>>,
+    ) -> Self {
+        let vk = &public_params.verifier_key.vk;
+        PreparedParams {
+            prepared_h: vk.h.into(),
+            prepared_beta_h: vk.beta_h.into(),
+            public_params,
+        }
+    }
+
+}
+
+impl<E: PairingEngine> Clone for PreparedParams<E> {
+
+    fn clone(&self) -> Self {
+        PreparedParams {
+            public_params: self.public_params.clone(),
+            prepared_h: self.prepared_h.clone(),
+            prepared_beta_h: self.prepared_beta_h.clone(),
+        }
+    }
+
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct EVSSPolynomial<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
     #[serde(serialize_with = "canonical_serialize")]
@@ -106,6 +416,117 @@ impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSSPolynomia
         }
     }
 
+    pub fn evaluate(&self, point: F) -> F {
+        self.polynomial.evaluate(&point)
+    }
+
+    /// Degree of the sharing polynomial; `reconstruct` needs at least `degree() + 1` shares.
+    pub fn degree(&self) -> usize {
+        self.polynomial.degree()
+    }
+
+    /// Debugging aid for dealers: opens a share at `point` and checks it against the
+    /// polynomial's own commitment, which fails if the polynomial changed after committing.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn self_check<R: RngCore>(&self, params: &EVSSParams<F, P, PC>, point: F, rng: &mut R) -> bool {
+        let share = match EVSS::get_share(point, params, self, rng) {
+            Ok(share) => share,
+            Err(_) => return false,
+        };
+        share.value == self.evaluate(point)
+            && EVSS::check(&params.get_public_params(), &self.get_commit(), &share, rng).unwrap_or(false)
+    }
+
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: HomomorphicPC<F, P>> EVSSPolynomial<F, P, PC> {
+
+    /// Sharing of the sum of both secrets; shares of it verify against the summed commitment.
+    pub fn add(&self, other: &Self) -> Self {
+        let mut polynomial = self.polynomial.clone();
+        polynomial += &other.polynomial;
+        EVSSPolynomial {
+            polynomial,
+            commit: PC::add_commitments(&self.commit, &other.commit),
+            rands: PC::add_randomness(&self.rands, &other.rands),
+        }
+    }
+
+    /// Sharing of `k` times the secret; shares of it verify against the scaled commitment.
+    pub fn scale(&self, k: F) -> Self {
+        let mut polynomial = P::zero();
+        polynomial += (k, &self.polynomial);
+        EVSSPolynomial {
+            polynomial,
+            commit: PC::scale_commitment(&self.commit, k),
+            rands: PC::scale_randomness(&self.rands, k),
+        }
+    }
+
+}
+
+impl<E, F> EVSSPolynomial<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>
+where
+    E: PairingEngine<Fr = F>,
+    F: PrimeField,
+{
+
+    /// Sets coefficient `index` to `new_value` and patches the commitment by adding
+    /// `(new_value - old) * [tau^index]`, a single scalar multiplication. The commitment
+    /// randomness is unchanged. Commitments with a degree bound are rejected.
+    pub fn update_coefficient(
+        &mut self,
+        index: usize,
+        new_value: F,
+        params: &EVSSParams<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>,
+    ) -> Result<(), EvssError<ark_poly_commit::Error>> {
+        if self.commit.shifted_comm.is_some() {
+            return Err(EvssError::DegreeBounded);
+        }
+        let power = match params.committer_key.powers.get(index) {
+            Some(power) if index <= params.degree => power,
+            _ => {
+                return Err(EvssError::DegreeExceeded {
+                    got: index,
+                    max: params.degree,
+                })
+            }
+        };
+        let coeffs = &mut self.polynomial.coeffs;
+        if coeffs.len() <= index {
+            coeffs.resize(index + 1, F::zero());
+        }
+        let delta = new_value - coeffs[index];
+        coeffs[index] = new_value;
+        while coeffs.last().is_some_and(|c| c.is_zero()) {
+            coeffs.pop();
+        }
+        let comm = self.commit.comm.0.into_projective() + power.mul(delta.into_repr());
+        self.commit.comm.0 = comm.into_affine();
+        Ok(())
+    }
+
+}
+
+/// Wipes the coefficients and the commitment randomness. Rust does not allow a `Drop` impl for
+/// one instantiation of a generic struct, so wrap the dealer's polynomial in
+/// `zeroize::Zeroizing` to have this run automatically when it goes out of scope.
+#[cfg(feature = "zeroize")]
+impl<E, F> zeroize::Zeroize
+    for EVSSPolynomial<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>
+where
+    E: PairingEngine<Fr = F>,
+    F: PrimeField,
+{
+
+    fn zeroize(&mut self) {
+        self.polynomial.coeffs.zeroize();
+        self.rands.rand.blinding_polynomial.coeffs.zeroize();
+        if let Some(shifted_rand) = &mut self.rands.shifted_rand {
+            shifted_rand.blinding_polynomial.coeffs.zeroize();
+        }
+    }
+
 }
 
 impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Debug for EVSSPolynomial<F, P, PC> {
@@ -137,10 +558,83 @@ pub struct EVSSCommit<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F,
     pub commit: PC::Commitment,
 }
 
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSSCommit<F, P, PC> {
+
+    pub fn serialized_size(&self) -> usize {
+        self.commit.serialized_size()
+    }
+
+    /// Labels the commitment once, for verifying many shares with `check_prepared_commit`.
+    pub fn prepare(&self) -> PreparedCommit<F, P, PC> {
+        self.prepare_labeled(DEFAULT_LABEL)
+    }
+
+    pub fn prepare_labeled(&self, label: &str) -> PreparedCommit<F, P, PC> {
+        PreparedCommit {
+            labeled: label_commit::<F, P, PC>(label, &self.commit),
+            _f: PhantomData,
+            _p: PhantomData,
+        }
+    }
+
+    /// The canonical bytes of the commitment, a stable identifier for the committed state.
+    pub fn digest(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = Vec::new();
+        self.commit.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        buf
+    }
+
+}
+
+/// A commitment already wrapped in the `LabeledCommitment` that `PC::check` expects, so hot
+/// verification loops do not clone the commitment and allocate its label on every share.
+pub struct PreparedCommit<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
+    labeled: LabeledCommitment<PC::Commitment>,
+    _f: PhantomData<F>,
+    _p: PhantomData<P>,
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for PreparedCommit<F, P, PC> {
+
+    fn clone(&self) -> Self {
+        PreparedCommit {
+            labeled: self.labeled.clone(),
+            _f: PhantomData,
+            _p: PhantomData,
+        }
+    }
+
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: HomomorphicPC<F, P>> EVSSCommit<F, P, PC> {
+
+    /// Computes `sum_i coeffs[i] * commits[i]`, the commitment to the same combination of the
+    /// committed polynomials.
+    pub fn linear_combination(commits: &[Self], coeffs: &[F]) -> Result<Self, EvssError<PC::Error>> {
+        if commits.len() != coeffs.len() {
+            return Err(EvssError::LengthMismatch {
+                expected: coeffs.len(),
+                got: commits.len(),
+            });
+        }
+        let mut terms = commits
+            .iter()
+            .zip(coeffs)
+            .map(|(c, &k)| PC::scale_commitment(&c.commit, k));
+        // Start from the first term: `PC::Commitment::empty()` may carry degree-bound parts.
+        let first = terms.next().unwrap_or_else(PC::Commitment::empty);
+        Ok(EVSSCommit {
+            commit: terms.fold(first, |acc, c| PC::add_commitments(&acc, &c)),
+        })
+    }
+
+}
+
 impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Debug for EVSSCommit<F, P, PC> {
 
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("EVSSCommit")
+         .field("commit", &fingerprint(&self.digest()))
          .finish()
     }
 
@@ -156,6 +650,49 @@ impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for EVS
 
 }
 
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> PartialEq for EVSSCommit<F, P, PC> {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.digest() == other.digest()
+    }
+
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Eq for EVSSCommit<F, P, PC> {}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Hash for EVSSCommit<F, P, PC> {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.digest().hash(state);
+    }
+
+}
+
+/// A participant's 1-based index, mapped to the evaluation point `F::from(index)`. Index 0
+/// is unrepresentable since its point would reveal the secret.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ParticipantIndex(u64);
+
+impl ParticipantIndex {
+
+    pub fn from_u64(index: u64) -> Option<Self> {
+        if index == 0 {
+            None
+        } else {
+            Some(ParticipantIndex(index))
+        }
+    }
+
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+
+    pub fn as_field<F: Field>(&self) -> F {
+        F::from(self.0)
+    }
+
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct EVSSShare<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
     #[serde(serialize_with = "canonical_serialize")]
@@ -172,6 +709,64 @@ pub struct EVSSShare<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P
     pub proof: PC::Proof,
 }
 
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSSShare<F, P, PC> {
+
+    pub fn from_parts(point: F, value: F, challenge: F, proof: PC::Proof) -> Self {
+        EVSSShare {
+            point,
+            value,
+            challenge,
+            proof,
+        }
+    }
+
+    /// Splits the share into `(point, value, challenge, proof)`.
+    pub fn into_proof_parts(self) -> (F, F, F, PC::Proof) {
+        (self.point, self.value, self.challenge, self.proof)
+    }
+
+    pub fn serialized_size(&self) -> usize {
+        self.point.serialized_size()
+            + self.value.serialized_size()
+            + self.challenge.serialized_size()
+            + self.proof.serialized_size()
+    }
+
+    /// A 32-byte identifier for deduplicating rebroadcast shares. It binds the share to
+    /// `commit`, so equal shares under the same commitment get equal ids.
+    pub fn id(&self, commit: &EVSSCommit<F, P, PC>) -> [u8; 32] {
+        let mut buf = commit.digest();
+        self.point.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        self.value.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        self.challenge.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        buf.extend_from_slice(&self.proof_bytes());
+        hash_to_bytes(SHARE_ID_DOMAIN, &buf)
+    }
+
+    // Proofs need not implement `PartialEq` or `Hash`, so they are compared by their bytes.
+    fn proof_bytes(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = Vec::new();
+        self.proof.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        buf
+    }
+
+}
+
+impl<E, F> EVSSShare<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>
+where
+    E: PairingEngine<Fr = F>,
+    F: PrimeField,
+{
+
+    /// The KZG witness `w` (the commitment to the quotient) and the claimed value, for
+    /// verifiers outside arkworks. They satisfy `e(C - value * g, h) = e(w, beta_h - point * h)`
+    /// for an unbounded, non-hiding commitment `C`.
+    pub fn proof_elements(&self) -> (E::G1Affine, F) {
+        (self.proof.w, self.value)
+    }
+
+}
+
 impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Debug for EVSSShare<F, P, PC> {
 
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -179,6 +774,7 @@ impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Deb
          .field("point", &self.point)
          .field("value", &self.value)
          .field("challenge", &self.challenge)
+         .field("proof", &fingerprint(&self.proof_bytes()))
          .finish()
     }
 
@@ -188,92 +784,1018 @@ impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for EVS
 
     fn clone(&self) -> Self {
         EVSSShare {
-            point: self.point.clone(),
-            value: self.value.clone(),
-            challenge: self.challenge.clone(),
+            point: self.point,
+            value: self.value,
+            challenge: self.challenge,
             proof: self.proof.clone(),
         }
     }
 
 }
 
-pub struct EVSS<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
-    _f: PhantomData<F>,
-    _p: PhantomData<P>,
-    _pc: PhantomData<PC>,
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> PartialEq for EVSSShare<F, P, PC> {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point
+            && self.value == other.value
+            && self.challenge == other.challenge
+            && self.proof_bytes() == other.proof_bytes()
+    }
+
 }
 
-impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSS<F, P, PC> {
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Eq for EVSSShare<F, P, PC> {}
 
-    pub fn setup<R: RngCore>(
-        degree: usize,
-        rng: &mut R,
-    ) -> Result<EVSSParams<F, P, PC>, PC::Error> {
-        let pp = PC::setup(degree, None, rng)?;
-        let (ck, vk) = PC::trim(&pp, degree, 0, None)?;
-        Ok(EVSSParams {
-            degree: degree,
-            committer_key: ck,
-            verifier_key: vk,
-        })
-    }
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Hash for EVSSShare<F, P, PC> {
 
-    pub fn commit<R: RngCore>(
-        pp: &EVSSParams<F, P, PC>,
-        secret: F,
-        rng: &mut R,
-    ) -> Result<EVSSPolynomial<F, P, PC>, PC::Error> {
-        let vec: Vec<F> = (0..pp.degree)
-            .map(|i| if i == 0 { secret } else { F::rand(rng) })
-            .collect();
-        let poly = label_polynomial(&P::from_coefficients_vec(vec));
-        let (lc, r) = PC::commit(&pp.committer_key, once(&poly), Some(rng))?;
-        Ok(EVSSPolynomial {
-            polynomial: poly.polynomial().clone(),
-            commit: lc[0].commitment().clone(),
-            rands: r[0].clone(),
-        })
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.point.hash(state);
+        self.value.hash(state);
+        self.challenge.hash(state);
+        self.proof_bytes().hash(state);
     }
 
-    pub fn get_share<R: RngCore>(
-        point: F,
-        params: &EVSSParams<F, P, PC>,
-        poly: &EVSSPolynomial<F, P, PC>,
-        rng: &mut R,
-    ) -> Result<EVSSShare<F, P, PC>, PC::Error> {
-        let ch = F::rand(rng);
-        let pr = PC::open(
-            &params.committer_key,
-            once(&label_polynomial(&poly.polynomial)),
-            once(&label_commit::<F, P, PC>(&poly.commit)),
-            &point,
-            ch,
-            once(&poly.rands),
-            Some(rng),
-        )?;
-        Ok(EVSSShare {
-            point: point,
-            value: poly.polynomial.evaluate(&point),
-            challenge: ch,
-            proof: pr,
-        })
-    }
+}
 
-    pub fn check<R: RngCore>(
-        params: &EVSSPublicParams<F, P, PC>,
-        commit: &EVSSCommit<F, P, PC>,
+/// Evaluations of one polynomial at several points under a single batch proof. arkworks 0.2
+/// KZG verifier keys have no G2 powers beyond `beta_h`, so the batch proof still holds one
+/// witness per point, but the verifier checks all of them with one randomized pairing check.
+#[derive(Serialize, Deserialize)]
+pub struct MultiShare<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub points: Vec<F>,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub values: Vec<F>,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub challenge: F,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub proof: PC::BatchProof,
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Debug for MultiShare<F, P, PC> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiShare")
+         .field("points", &self.points)
+         .field("values", &self.values)
+         .field("challenge", &self.challenge)
+         .finish()
+    }
+
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for MultiShare<F, P, PC> {
+
+    fn clone(&self) -> Self {
+        MultiShare {
+            points: self.points.clone(),
+            values: self.values.clone(),
+            challenge: self.challenge,
+            proof: self.proof.clone(),
+        }
+    }
+
+}
+
+/// Non-interactive proof that the dealer can open the commitment at 0. The dealer commits to
+/// a random mask `m`, derives `c` from both commitments, and opens `m + c * p` at 0; the mask
+/// hides the secret while two answers to different `c` would reveal it.
+#[derive(Serialize, Deserialize)]
+pub struct KnowledgeProof<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub mask_commit: PC::Commitment,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub value: F,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub proof: PC::Proof,
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Debug for KnowledgeProof<F, P, PC> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KnowledgeProof")
+         .field("value", &self.value)
+         .finish()
+    }
+
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for KnowledgeProof<F, P, PC> {
+
+    fn clone(&self) -> Self {
+        KnowledgeProof {
+            mask_commit: self.mask_commit.clone(),
+            value: self.value,
+            proof: self.proof.clone(),
+        }
+    }
+
+}
+
+/// Public accusation that the dealer handed out `share` although it fails against the
+/// commitment. Anyone can re-run the check. Revealing the share is only evidence against the
+/// dealer if the share is known to come from them, e.g. because it was signed; otherwise the
+/// dealer should answer by publishing a valid share for the same point.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Complaint<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
+    pub share: EVSSShare<F, P, PC>,
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Debug for Complaint<F, P, PC> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Complaint")
+         .field("share", &self.share)
+         .finish()
+    }
+
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for Complaint<F, P, PC> {
+
+    fn clone(&self) -> Self {
+        Complaint {
+            share: self.share.clone(),
+        }
+    }
+
+}
+
+/// A complete dealing: the commitment together with every participant's share.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Dealing<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
+    pub commit: EVSSCommit<F, P, PC>,
+    pub shares: Vec<EVSSShare<F, P, PC>>,
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Dealing<F, P, PC> {
+
+    /// Whether every share verifies against the commitment.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn verify_all<R: RngCore>(
+        &self,
+        params: &EVSSPublicParams<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        for sh in &self.shares {
+            if !EVSS::check(params, &self.commit, sh, rng)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> std::fmt::Debug for Dealing<F, P, PC> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Dealing")
+         .field("commit", &self.commit)
+         .field("shares", &self.shares)
+         .finish()
+    }
+
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> Clone for Dealing<F, P, PC> {
+
+    fn clone(&self) -> Self {
+        Dealing {
+            commit: self.commit.clone(),
+            shares: self.shares.clone(),
+        }
+    }
+
+}
+
+/// Detailed result of verifying a share. A wrong value fails the opening exactly like a
+/// forged proof, so each entry point returns only some of the variants:
+///
+/// - `EVSS::check_detailed`: `Valid` or `ProofInvalid`.
+/// - `EVSS::check_value`: `Valid`, `ProofInvalid` or `ValueMismatch`.
+/// - `Biaccumulator::check_detailed`: `Valid`, `ProofInvalid` or `NotAMember`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckOutcome {
+    Valid,
+    ProofInvalid,
+    /// The opening is valid but of a value other than the expected one.
+    ValueMismatch,
+    /// The opening is of a nonzero value, so the point is not in the accumulated set.
+    NotAMember,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct EVSS<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> {
+    _f: PhantomData<F>,
+    _p: PhantomData<P>,
+    _pc: PhantomData<PC>,
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSS<F, P, PC> {
+
+    pub fn setup<R: RngCore>(
+        degree: usize,
+        rng: &mut R,
+    ) -> Result<EVSSParams<F, P, PC>, EvssError<PC::Error>> {
+        Self::setup_with_degree_bounds(degree, &[], rng)
+    }
+
+    /// Like `setup`, but the committer key can blind commitments with any hiding bound up to
+    /// `hiding_bound` (see `commit_with_hiding_bound`).
+    pub fn setup_with_hiding_bound<R: RngCore>(
+        degree: usize,
+        hiding_bound: usize,
+        rng: &mut R,
+    ) -> Result<EVSSParams<F, P, PC>, EvssError<PC::Error>> {
+        if degree == 0 {
+            return Err(EvssError::DegreeIsZero);
+        }
+        let pp = PC::setup(degree, None, rng)?;
+        Self::trim(&pp, degree, hiding_bound, &[])
+    }
+
+    /// Like `setup`, but the keys can also commit to and verify polynomials under any of
+    /// `degree_bounds` (see `commit_bounded`).
+    pub fn setup_with_degree_bounds<R: RngCore>(
+        degree: usize,
+        degree_bounds: &[usize],
+        rng: &mut R,
+    ) -> Result<EVSSParams<F, P, PC>, EvssError<PC::Error>> {
+        if degree == 0 {
+            return Err(EvssError::DegreeIsZero);
+        }
+        if let Some(&bound) = degree_bounds.iter().find(|&&bound| bound > degree) {
+            return Err(EvssError::DegreeExceeded {
+                got: bound,
+                max: degree,
+            });
+        }
+        let pp = PC::setup(degree, None, rng)?;
+        Self::trim(&pp, degree, 0, degree_bounds)
+    }
+
+    /// Trims a universal SRS generated once by `PC::setup`, so several degrees can share it.
+    pub fn setup_from_universal(
+        pp: &PC::UniversalParams,
+        degree: usize,
+    ) -> Result<EVSSParams<F, P, PC>, EvssError<PC::Error>> {
+        if degree == 0 {
+            return Err(EvssError::DegreeIsZero);
+        }
+        if degree > pp.max_degree() {
+            return Err(EvssError::DegreeExceeded {
+                got: degree,
+                max: pp.max_degree(),
+            });
+        }
+        Self::trim(pp, degree, 0, &[])
+    }
+
+    fn trim(
+        pp: &PC::UniversalParams,
+        degree: usize,
+        hiding_bound: usize,
+        degree_bounds: &[usize],
+    ) -> Result<EVSSParams<F, P, PC>, EvssError<PC::Error>> {
+        let enforced = Some(degree_bounds).filter(|bounds| !bounds.is_empty());
+        let (ck, vk) = PC::trim(pp, degree, hiding_bound, enforced)?;
+        Ok(EVSSParams {
+            degree,
+            hiding_bound,
+            committer_key: ck,
+            verifier_key: vk,
+        })
+    }
+
+    pub fn commit<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secret: F,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        Self::commit_at(pp, secret, 0, rng)
+    }
+
+    /// `commit` for an integer secret. Like the rest of `EVSS` this works for every field,
+    /// not only the curves instantiated by `define_evss!`.
+    pub fn commit_u64<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secret: u64,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        Self::commit(pp, F::from(secret), rng)
+    }
+
+    pub fn commit_at<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secret: F,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        let poly = Self::random_polynomial(pp, secret, secret_index, rng)?;
+        Self::commit_polynomial(pp, &poly, rng)
+    }
+
+    /// `commit` under `label` instead of `DEFAULT_LABEL`, for callers that hand this polynomial
+    /// to `PC` together with others. Open and verify with `get_share_labeled` and `check_labeled`.
+    pub fn commit_labeled<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secret: F,
+        label: &str,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        let poly = Self::random_polynomial(pp, secret, 0, rng)?;
+        Self::commit_labeled_polynomial(pp, &poly, label, None, rng)
+    }
+
+    /// `pp.degree` coefficients, all random except `secret` at `secret_index`.
+    fn random_polynomial<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secret: F,
+        secret_index: usize,
+        rng: &mut R,
+    ) -> Result<P, EvssError<PC::Error>> {
+        if secret_index >= pp.degree {
+            return Err(EvssError::DegreeExceeded {
+                got: secret_index,
+                max: pp.degree.saturating_sub(1),
+            });
+        }
+        let vec: Vec<F> = (0..pp.degree)
+            .map(|i| if i == secret_index { secret } else { F::rand(rng) })
+            .collect();
+        Ok(P::from_coefficients_vec(vec))
+    }
+
+    pub fn commit_polynomial<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        poly: &P,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        Self::commit_polynomial_with_hiding_bound(pp, poly, None, rng)
+    }
+
+    /// Like `commit`, but with `Some(hiding_bound)` the commitment is blinded so it hides the
+    /// polynomial. The bound may not exceed `pp.hiding_bound`, see `setup_with_hiding_bound`.
+    pub fn commit_with_hiding_bound<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secret: F,
+        hiding_bound: Option<usize>,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        let poly = Self::random_polynomial(pp, secret, 0, rng)?;
+        Self::commit_polynomial_with_hiding_bound(pp, &poly, hiding_bound, rng)
+    }
+
+    pub fn commit_polynomial_with_hiding_bound<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        poly: &P,
+        hiding_bound: Option<usize>,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        Self::commit_labeled_polynomial(pp, poly, DEFAULT_LABEL, hiding_bound, rng)
+    }
+
+    fn commit_labeled_polynomial<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        poly: &P,
+        label: &str,
+        hiding_bound: Option<usize>,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        if poly.degree() > pp.degree {
+            return Err(EvssError::DegreeExceeded {
+                got: poly.degree(),
+                max: pp.degree,
+            });
+        }
+        if let Some(got) = hiding_bound.filter(|&bound| bound > pp.hiding_bound) {
+            return Err(EvssError::HidingBoundExceeded {
+                got,
+                max: pp.hiding_bound,
+            });
+        }
+        let poly = label_polynomial_hiding(label, poly, hiding_bound);
+        let (lc, r) = PC::commit(&pp.committer_key, once(&poly), Some(rng))?;
+        Ok(EVSSPolynomial {
+            polynomial: poly.polynomial().clone(),
+            commit: lc[0].commitment().clone(),
+            rands: r[0].clone(),
+        })
+    }
+
+    /// Commits to all of `polys` in one `PC::commit` call, each under its index as label.
+    #[allow(clippy::type_complexity)]
+    pub fn commit_batch<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        polys: &[P],
+        rng: &mut R,
+    ) -> Result<Vec<EVSSPolynomial<F, P, PC>>, EvssError<PC::Error>> {
+        if let Some(poly) = polys.iter().find(|poly| poly.degree() > pp.degree) {
+            return Err(EvssError::DegreeExceeded {
+                got: poly.degree(),
+                max: pp.degree,
+            });
+        }
+        let labeled: Vec<_> = polys
+            .iter()
+            .enumerate()
+            .map(|(i, poly)| label_polynomial(&i.to_string(), poly))
+            .collect();
+        let (lc, r) = PC::commit(&pp.committer_key, &labeled, Some(rng))?;
+        Ok(polys
+            .iter()
+            .zip(lc)
+            .zip(r)
+            .map(|((poly, commit), rands)| EVSSPolynomial {
+                polynomial: poly.clone(),
+                commit: commit.commitment().clone(),
+                rands,
+            })
+            .collect())
+    }
+
+    /// Commits to a polynomial of degree at most `degree_bound` with `secret` as its constant
+    /// term, attaching a proof of the bound. `pp` must come from `setup_with_degree_bounds`
+    /// with `degree_bound` among the bounds, and shares must use `get_share_bounded`.
+    pub fn commit_bounded<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secret: F,
+        degree_bound: usize,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        if degree_bound > pp.degree {
+            return Err(EvssError::DegreeExceeded {
+                got: degree_bound,
+                max: pp.degree,
+            });
+        }
+        let vec: Vec<F> = once(secret).chain((0..degree_bound).map(|_| F::rand(rng))).collect();
+        let poly = label_polynomial_bounded(DEFAULT_LABEL, &P::from_coefficients_vec(vec), Some(degree_bound));
+        let (lc, r) = PC::commit(&pp.committer_key, once(&poly), Some(rng))?;
+        Ok(EVSSPolynomial {
+            polynomial: poly.polynomial().clone(),
+            commit: lc[0].commitment().clone(),
+            rands: r[0].clone(),
+        })
+    }
+
+    /// Packed sharing: commits to a polynomial with `secrets[i]` at `secret_points[i]` and
+    /// randomness elsewhere. Share at points outside `secret_points` and recover the secrets
+    /// with `reconstruct_packed`.
+    pub fn commit_packed<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secrets: &[F],
+        secret_points: &[F],
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        if secrets.len() != secret_points.len() {
+            return Err(EvssError::LengthMismatch {
+                expected: secrets.len(),
+                got: secret_points.len(),
+            });
+        }
+        if secrets.len() > pp.degree {
+            return Err(EvssError::DegreeExceeded {
+                got: secrets.len(),
+                max: pp.degree,
+            });
+        }
+        if secret_points.iter().collect::<BTreeSet<_>>().len() != secret_points.len() {
+            return Err(EvssError::DuplicatePoint);
+        }
+        // interpolant + vanishing(secret_points) * random, with pp.degree coefficients in total
+        let mut masked: Vec<F> = (secrets.len()..pp.degree).map(|_| F::rand(rng)).collect();
+        for &point in secret_points {
+            masked = mul_by_linear(&masked, point);
+        }
+        let interpolant = interpolate_polynomial(secret_points, secrets);
+        for (c, &i) in masked.iter_mut().zip(&interpolant) {
+            *c += i;
+        }
+        Self::commit_polynomial(pp, &P::from_coefficients_vec(masked), rng)
+    }
+
+    pub fn commit_deterministic(
+        pp: &EVSSParams<F, P, PC>,
+        secret: F,
+        seed: [u8; 32],
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        Self::commit(pp, secret, &mut ChaCha20Rng::from_seed(seed))
+    }
+
+    /// Commits to each secret with its own ChaCha20 RNG seeded from `rng`, so the result is
+    /// the same with or without the `parallel` feature. The output follows `secrets`' order.
+    #[allow(clippy::type_complexity)]
+    pub fn commit_many<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secrets: &[F],
+        rng: &mut R,
+    ) -> Result<Vec<EVSSPolynomial<F, P, PC>>, EvssError<PC::Error>>
+    where
+        EVSSParams<F, P, PC>: Sync,
+        EVSSPolynomial<F, P, PC>: Send,
+        PC::Error: Send,
+    {
+        let seeds: Vec<[u8; 32]> = secrets
+            .iter()
+            .map(|_| {
+                let mut seed = [0u8; 32];
+                rng.fill_bytes(&mut seed);
+                seed
+            })
+            .collect();
+        cfg_iter!(secrets)
+            .zip(cfg_iter!(seeds))
+            .map(|(&secret, &seed)| Self::commit_deterministic(pp, secret, seed))
+            .collect()
+    }
+
+    pub fn get_share<R: RngCore>(
+        point: F,
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        Self::get_share_labeled(point, params, poly, DEFAULT_LABEL, rng)
+    }
+
+    /// `get_share` for a polynomial committed with `commit_labeled`.
+    pub fn get_share_labeled<R: RngCore>(
+        point: F,
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        label: &str,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        let ch = F::rand(rng);
+        Self::open_share(point, ch, label, None, params, poly, rng)
+    }
+
+    /// Like `get_share`, but opens with a challenge agreed out of band, e.g. from a beacon.
+    pub fn get_share_with_challenge<R: RngCore>(
+        point: F,
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        challenge: F,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        Self::open_share(point, challenge, DEFAULT_LABEL, None, params, poly, rng)
+    }
+
+    pub fn get_share_for_index<R: RngCore>(
+        index: ParticipantIndex,
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        Self::get_share(index.as_field(), params, poly, rng)
+    }
+
+    /// Shares at the participant index `index`, refusing 0 with `ZeroPoint`. Generic like
+    /// `commit_u64`.
+    pub fn get_share_u64<R: RngCore>(
+        index: u64,
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        let index = ParticipantIndex::from_u64(index).ok_or(EvssError::ZeroPoint)?;
+        Self::get_share_for_index(index, params, poly, rng)
+    }
+
+    /// Like `get_share`, but refuses point 0, whose value is the secret itself, and any point
+    /// already in `used`.
+    pub fn get_share_checked<R: RngCore>(
+        point: F,
+        used: &[F],
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        if point.is_zero() {
+            return Err(EvssError::ZeroPoint);
+        }
+        if used.contains(&point) {
+            return Err(EvssError::DuplicatePoint);
+        }
+        Self::get_share(point, params, poly, rng)
+    }
+
+    /// Opens a `commit_bounded` polynomial with the Fiat-Shamir challenge `check_bounded`
+    /// recomputes, so the dealer cannot pick one that skips the degree-bound term.
+    pub fn get_share_bounded<R: RngCore>(
+        point: F,
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        degree_bound: usize,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        let ch = Self::transcript_challenge(&mut Blake2bTranscript::new(), &poly.commit, &point, &poly.evaluate(point));
+        Self::open_share(point, ch, DEFAULT_LABEL, Some(degree_bound), params, poly, rng)
+    }
+
+    pub fn get_share_fs<R: RngCore>(
+        point: F,
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        Self::get_share_with_transcript(point, params, poly, Blake2bTranscript::new(), rng)
+    }
+
+    /// Like `get_share_fs`, with the challenge drawn from `transcript` after it absorbs the
+    /// commitment, point and value. Verify with `check_with_transcript` and an equal transcript.
+    pub fn get_share_with_transcript<T: EvssTranscript<F>, R: RngCore>(
+        point: F,
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        mut transcript: T,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        let ch = Self::transcript_challenge(&mut transcript, &poly.commit, &point, &poly.evaluate(point));
+        Self::open_share(point, ch, DEFAULT_LABEL, None, params, poly, rng)
+    }
+
+    fn transcript_challenge<T: EvssTranscript<F>>(
+        transcript: &mut T,
+        commit: &PC::Commitment,
+        point: &F,
+        value: &F,
+    ) -> F {
+        transcript.append_commitment(commit);
+        transcript.append_point(point);
+        transcript.append_value(value);
+        transcript.challenge_scalar()
+    }
+
+    fn open_share<R: RngCore>(
+        point: F,
+        challenge: F,
+        label: &str,
+        degree_bound: Option<usize>,
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        let pr = PC::open(
+            &params.committer_key,
+            once(&label_polynomial_bounded(label, &poly.polynomial, degree_bound)),
+            once(&label_commit_bounded::<F, P, PC>(label, &poly.commit, degree_bound)),
+            &point,
+            challenge,
+            once(&poly.rands),
+            Some(rng),
+        )?;
+        Ok(EVSSShare {
+            point,
+            value: poly.evaluate(point),
+            challenge,
+            proof: pr,
+        })
+    }
+
+    /// Opens `poly` at every point in `points` with one batch proof.
+    pub fn get_share_multipoint<R: RngCore>(
+        points: &[F],
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<MultiShare<F, P, PC>, EvssError<PC::Error>> {
+        let ch = F::rand(rng);
+        let proof = PC::batch_open(
+            &params.committer_key,
+            once(&label_polynomial(DEFAULT_LABEL, &poly.polynomial)),
+            once(&label_commit::<F, P, PC>(DEFAULT_LABEL, &poly.commit)),
+            &Self::multipoint_query_set(points),
+            ch,
+            once(&poly.rands),
+            Some(rng),
+        )?;
+        Ok(MultiShare {
+            points: points.to_vec(),
+            values: points.iter().map(|&point| poly.evaluate(point)).collect(),
+            challenge: ch,
+            proof,
+        })
+    }
+
+    fn multipoint_query_set(points: &[F]) -> QuerySet<F> {
+        points
+            .iter()
+            .enumerate()
+            .map(|(i, &point)| (DEFAULT_LABEL.to_owned(), (i.to_string(), point)))
+            .collect()
+    }
+
+    /// Opens one share per point. Only the labelled polynomial and commitment are built once;
+    /// each share still costs a full `PC::open` with its own challenge, since arkworks 0.2 has
+    /// no way to amortize single-point witnesses. Use `get_share_multipoint` for one proof
+    /// over many points.
+    #[allow(clippy::type_complexity)]
+    pub fn get_shares<R: RngCore>(
+        points: &[F],
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<Vec<EVSSShare<F, P, PC>>, EvssError<PC::Error>> {
+        let values: Vec<F> = points.iter().map(|&point| poly.evaluate(point)).collect();
+        Self::open_shares(points, &values, params, poly, rng)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn open_shares<R: RngCore>(
+        points: &[F],
+        values: &[F],
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<Vec<EVSSShare<F, P, PC>>, EvssError<PC::Error>> {
+        let labeled_poly = label_polynomial(DEFAULT_LABEL, &poly.polynomial);
+        let labeled_commit = label_commit::<F, P, PC>(DEFAULT_LABEL, &poly.commit);
+        let mut shares = Vec::with_capacity(points.len());
+        for (point, value) in points.iter().zip(values) {
+            let ch = F::rand(rng);
+            let pr = PC::open(
+                &params.committer_key,
+                once(&labeled_poly),
+                once(&labeled_commit),
+                point,
+                ch,
+                once(&poly.rands),
+                Some(rng),
+            )?;
+            shares.push(EVSSShare {
+                point: *point,
+                value: *value,
+                challenge: ch,
+                proof: pr,
+            });
+        }
+        Ok(shares)
+    }
+
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        Self::check_labeled(params, commit, share, DEFAULT_LABEL, rng)
+    }
+
+    /// `check` for a share opened with `get_share_labeled`.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_labeled<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        label: &str,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        Self::check_prepared_commit(params, &commit.prepare_labeled(label), share, rng)
+    }
+
+    /// `check` against a commitment prepared once with `EVSSCommit::prepare`.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_prepared_commit<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &PreparedCommit<F, P, PC>,
         share: &EVSSShare<F, P, PC>,
         rng: &mut R,
-    ) -> Result<bool, PC::Error> {
-        PC::check(
+    ) -> Result<bool, EvssError<PC::Error>> {
+        Ok(PC::check(
             &params.verifier_key,
-            once(&label_commit::<F, P, PC>(&commit.commit)),
+            once(&commit.labeled),
             &share.point,
             once(share.value),
             &share.proof,
             share.challenge,
             Some(rng),
-        )
+        )?)
+    }
+
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_multipoint<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &MultiShare<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        if share.points.len() != share.values.len() {
+            return Err(EvssError::LengthMismatch {
+                expected: share.points.len(),
+                got: share.values.len(),
+            });
+        }
+        let evaluations: Evaluations<F, F> = share
+            .points
+            .iter()
+            .zip(&share.values)
+            .map(|(&point, &value)| ((DEFAULT_LABEL.to_owned(), point), value))
+            .collect();
+        Ok(PC::batch_check(
+            &params.verifier_key,
+            once(&label_commit::<F, P, PC>(DEFAULT_LABEL, &commit.commit)),
+            &Self::multipoint_query_set(&share.points),
+            &evaluations,
+            &share.proof,
+            share.challenge,
+            rng,
+        )?)
+    }
+
+    /// `check` as a `CheckOutcome`, which is only ever `Valid` or `ProofInvalid`; use
+    /// `check_value` to also tell a valid opening of the wrong value apart.
+    #[must_use = "the outcome indicates whether verification succeeded"]
+    pub fn check_detailed<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<CheckOutcome, EvssError<PC::Error>> {
+        if Self::check(params, commit, share, rng)? {
+            Ok(CheckOutcome::Valid)
+        } else {
+            Ok(CheckOutcome::ProofInvalid)
+        }
+    }
+
+    /// Like `check_detailed`, but a valid opening of a value other than `expected` is reported
+    /// as `ValueMismatch`.
+    #[must_use = "the outcome indicates whether verification succeeded"]
+    pub fn check_value<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        expected: F,
+        rng: &mut R,
+    ) -> Result<CheckOutcome, EvssError<PC::Error>> {
+        match Self::check_detailed(params, commit, share, rng)? {
+            CheckOutcome::Valid if share.value != expected => Ok(CheckOutcome::ValueMismatch),
+            outcome => Ok(outcome),
+        }
+    }
+
+    /// Returns a complaint if `share` fails against `commit`, and `None` if it is valid.
+    #[allow(clippy::type_complexity)]
+    pub fn make_complaint<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<Option<Complaint<F, P, PC>>, EvssError<PC::Error>> {
+        if Self::check(params, commit, share, rng)? {
+            return Ok(None);
+        }
+        Ok(Some(Complaint {
+            share: share.clone(),
+        }))
+    }
+
+    /// Holds exactly when the revealed share genuinely fails against `commit`.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn verify_complaint<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        complaint: &Complaint<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        Ok(!Self::check(params, commit, &complaint.share, rng)?)
+    }
+
+    /// Like `check`, but also requires the share to have been opened with `challenge`.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_with_challenge<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        challenge: F,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        if share.challenge != challenge {
+            return Ok(false);
+        }
+        Self::check(params, commit, share, rng)
+    }
+
+    /// Like `check`, but also requires the share to be the one dealt to `index`.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_for_index<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        index: ParticipantIndex,
+        share: &EVSSShare<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        if share.point != index.as_field() {
+            return Ok(false);
+        }
+        Self::check(params, commit, share, rng)
+    }
+
+    /// Verifies a `get_share_bounded` share, including the degree-bound part of the opening.
+    /// The challenge is recomputed from the commitment, point and value; `share.challenge` is
+    /// ignored. The bound only binds at points the dealer cannot predict: at fixed, public
+    /// share points a dealer can fit a fake shifted polynomial through them, so use
+    /// `check_degree` on the full set of shares as well.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_bounded<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        degree_bound: usize,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        if !commit.commit.has_degree_bound() {
+            return Ok(false);
+        }
+        let ch = Self::transcript_challenge(&mut Blake2bTranscript::new(), &commit.commit, &share.point, &share.value);
+        Ok(PC::check(
+            &params.verifier_key,
+            once(&label_commit_bounded::<F, P, PC>(DEFAULT_LABEL, &commit.commit, Some(degree_bound))),
+            &share.point,
+            once(share.value),
+            &share.proof,
+            ch,
+            Some(rng),
+        )?)
+    }
+
+    /// Checks every share against `commit` and that together they lie on one polynomial of
+    /// degree at most `degree_bound`. Needs `params.threshold()` shares at distinct points:
+    /// keys from `setup` have no higher powers, so those shares pin down the committed
+    /// polynomial and it cannot hide a higher degree between them.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_degree<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        shares: &[EVSSShare<F, P, PC>],
+        degree_bound: usize,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        Self::validate_shares(shares, params.threshold())?;
+        let prepared = commit.prepare();
+        for sh in shares {
+            if !Self::check_prepared_commit(params, &prepared, sh, rng)? {
+                return Ok(false);
+            }
+        }
+        Ok(Self::reconstruct_polynomial(shares)?.degree() <= degree_bound)
+    }
+
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_at_indices<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        allowed: &[F],
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        if !allowed.contains(&share.point) {
+            return Ok(false);
+        }
+        Self::check(params, commit, share, rng)
+    }
+
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_fs<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        Self::check_with_transcript(params, commit, share, Blake2bTranscript::new(), rng)
+    }
+
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_with_transcript<T: EvssTranscript<F>, R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        mut transcript: T,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        Ok(PC::check(
+            &params.verifier_key,
+            once(&label_commit::<F, P, PC>(DEFAULT_LABEL, &commit.commit)),
+            &share.point,
+            once(share.value),
+            &share.proof,
+            Self::transcript_challenge(&mut transcript, &commit.commit, &share.point, &share.value),
+            Some(rng),
+        )?)
     }
 
     pub fn reconstruct<'a, I>(shares: &'a I) -> F
@@ -282,17 +1804,651 @@ impl<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSS<F, P, PC
         P: 'a,
         PC: 'a,
     {
-        let mut res = F::zero();
-        for sh1 in shares {
-            let mut term = sh1.value;
-            for sh2 in shares {
-                if sh1.point != sh2.point {
-                    term *= (-sh2.point) / (sh1.point - sh2.point)
+        Self::interpolate(shares, F::zero())
+    }
+
+    /// `reconstruct` for a plain slice of shares.
+    pub fn reconstruct_slice(shares: &[EVSSShare<F, P, PC>]) -> F {
+        Self::interpolate(shares, F::zero())
+    }
+
+    /// Reconstruction from bare evaluations whose proofs were already checked and dropped.
+    pub fn reconstruct_from_evaluations(
+        points: &[F],
+        values: &[F],
+    ) -> Result<F, EvssError<PC::Error>> {
+        if points.len() != values.len() {
+            return Err(EvssError::LengthMismatch {
+                expected: points.len(),
+                got: values.len(),
+            });
+        }
+        if points.is_empty() {
+            return Err(EvssError::InsufficientShares { have: 0, need: 1 });
+        }
+        if points.iter().collect::<BTreeSet<_>>().len() != points.len() {
+            return Err(EvssError::DuplicatePoint);
+        }
+        Ok(Self::weighted_sum(values, &lagrange_coefficients(points)))
+    }
+
+    /// Weighted reconstruction, where participant `i` holds the next `weights[i]` entries of
+    /// `shares` as `(point, value)` pairs. A participant of weight `w` is simply `w` ordinary
+    /// shareholders, so this is `reconstruct_from_evaluations` over all pairs once the total
+    /// weight is known to reach `threshold + 1`.
+    pub fn reconstruct_weighted(
+        shares: &[(F, F)],
+        weights: &[usize],
+        threshold: usize,
+    ) -> Result<F, EvssError<PC::Error>> {
+        let total: usize = weights.iter().sum();
+        if total != shares.len() {
+            return Err(EvssError::LengthMismatch {
+                expected: total,
+                got: shares.len(),
+            });
+        }
+        if total < threshold + 1 {
+            return Err(EvssError::InsufficientShares {
+                have: total,
+                need: threshold + 1,
+            });
+        }
+        let (points, values): (Vec<F>, Vec<F>) = shares.iter().copied().unzip();
+        Self::reconstruct_from_evaluations(&points, &values)
+    }
+
+    /// Whether `shares` hold at least `params.threshold()` distinct points.
+    pub fn can_reconstruct(
+        params: &EVSSPublicParams<F, P, PC>,
+        shares: &[EVSSShare<F, P, PC>],
+    ) -> bool {
+        shares.iter().map(|sh| sh.point).collect::<BTreeSet<_>>().len() >= params.threshold()
+    }
+
+    /// Requires at least `params.degree + 1` shares with distinct points, so a lone share is
+    /// rejected instead of being returned as the secret. Fails with `InsufficientShares` or
+    /// `DuplicatePoint`.
+    pub fn try_reconstruct(
+        params: &EVSSPublicParams<F, P, PC>,
+        shares: &[EVSSShare<F, P, PC>],
+    ) -> Result<F, EvssError<PC::Error>> {
+        Self::validate_shares(shares, params.threshold())?;
+        Ok(Self::interpolate(shares.iter(), F::zero()))
+    }
+
+    /// Checks every share against `commit`, drops the invalid ones and reconstructs from the
+    /// rest, which must still contain `degree + 1` distinct points.
+    pub fn reconstruct_verified<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        shares: &[EVSSShare<F, P, PC>],
+        rng: &mut R,
+    ) -> Result<F, EvssError<PC::Error>> {
+        let mut valid = Vec::with_capacity(shares.len());
+        for sh in shares {
+            if Self::check(params, commit, sh, rng)? {
+                valid.push(sh.clone());
+            }
+        }
+        Self::try_reconstruct(params, &valid)
+    }
+
+    /// Interpolates over the first `threshold + 1` shares with distinct points, skipping any
+    /// share whose point was already taken and ignoring the rest.
+    pub fn reconstruct_with_threshold(
+        shares: &[EVSSShare<F, P, PC>],
+        threshold: usize,
+    ) -> Result<F, EvssError<PC::Error>> {
+        let mut points = BTreeSet::new();
+        let selected: Vec<&EVSSShare<F, P, PC>> = shares
+            .iter()
+            .filter(|sh| points.insert(sh.point))
+            .take(threshold + 1)
+            .collect();
+        if selected.len() < threshold + 1 {
+            return Err(EvssError::InsufficientShares {
+                have: selected.len(),
+                need: threshold + 1,
+            });
+        }
+        Ok(Self::interpolate(selected, F::zero()))
+    }
+
+    /// Reconstruction whose control flow depends only on the share points, which are public
+    /// indices, and never on the secret values: the points are checked for distinctness up
+    /// front and the values then go through a uniform multiply-accumulate. Timing is only as
+    /// value-independent as the underlying arkworks field arithmetic.
+    pub fn reconstruct_ct(
+        shares: &[EVSSShare<F, P, PC>],
+    ) -> Result<F, EvssError<PC::Error>> {
+        Self::validate_shares(shares, 1)?;
+        let (points, values): (Vec<F>, Vec<F>) =
+            shares.iter().map(|sh| (sh.point, sh.value)).unzip();
+        Ok(Self::weighted_sum(&values, &lagrange_coefficients(&points)))
+    }
+
+    /// Compares two secrets, e.g. ones reconstructed by different committees, without
+    /// branching on their bytes. As with `reconstruct_ct`, share points are public and may
+    /// still be compared with `==`.
+    pub fn secret_eq_ct(a: &F, b: &F) -> Choice {
+        let mut a_bytes: Vec<u8> = Vec::new();
+        let mut b_bytes: Vec<u8> = Vec::new();
+        a.serialize(&mut a_bytes).expect("serializing into a vector cannot fail");
+        b.serialize(&mut b_bytes).expect("serializing into a vector cannot fail");
+        a_bytes.ct_eq(&b_bytes)
+    }
+
+    pub fn reconstruct_packed(
+        shares: &[EVSSShare<F, P, PC>],
+        secret_points: &[F],
+    ) -> Result<Vec<F>, EvssError<PC::Error>> {
+        Self::validate_shares(shares, 1)?;
+        let (points, values): (Vec<F>, Vec<F>) =
+            shares.iter().map(|sh| (sh.point, sh.value)).unzip();
+        Ok(secret_points
+            .iter()
+            .map(|&x0| {
+                Self::weighted_sum(&values, &lagrange_coefficients_at(&points, x0))
+            })
+            .collect())
+    }
+
+    /// Berlekamp–Welch decoding of a polynomial of degree at most `threshold` from at least
+    /// `threshold + 1 + 2 * max_errors` shares, up to `max_errors` of which may carry wrong
+    /// values. Returns the secret and the indices into `shares` of the faulty ones.
+    pub fn reconstruct_correcting(
+        shares: &[EVSSShare<F, P, PC>],
+        threshold: usize,
+        max_errors: usize,
+    ) -> Result<(F, Vec<usize>), EvssError<PC::Error>> {
+        Self::validate_shares(shares, threshold + 1 + 2 * max_errors)?;
+        // Unknowns: the coefficients of Q (degree threshold + max_errors) and of the monic
+        // error locator E (degree max_errors), constrained by Q(x_i) = y_i * E(x_i).
+        let q_len = threshold + max_errors + 1;
+        let rows = shares
+            .iter()
+            .map(|sh| {
+                let powers: Vec<F> = (0..=q_len)
+                    .scan(F::one(), |acc, _| {
+                        let cur = *acc;
+                        *acc *= sh.point;
+                        Some(cur)
+                    })
+                    .collect();
+                powers[..q_len]
+                    .iter()
+                    .copied()
+                    .chain(powers[..max_errors].iter().map(|&x| -sh.value * x))
+                    .chain(once(sh.value * powers[max_errors]))
+                    .collect()
+            })
+            .collect();
+        let solution =
+            solve_linear_system(rows, q_len + max_errors).ok_or(EvssError::TooManyErrors)?;
+        let locator: Vec<F> = solution[q_len..].iter().copied().chain(once(F::one())).collect();
+        let (coeffs, rem) = divide_polynomial(&solution[..q_len], &locator);
+        if rem.iter().any(|c| !c.is_zero()) {
+            return Err(EvssError::TooManyErrors);
+        }
+        let faulty: Vec<usize> = shares
+            .iter()
+            .enumerate()
+            .filter(|(_, sh)| {
+                coeffs.iter().rev().fold(F::zero(), |acc, &c| acc * sh.point + c) != sh.value
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if faulty.len() > max_errors {
+            return Err(EvssError::TooManyErrors);
+        }
+        Ok((coeffs.first().copied().unwrap_or_else(F::zero), faulty))
+    }
+
+    pub fn reconstruct_at(
+        shares: &[EVSSShare<F, P, PC>],
+        x0: F,
+    ) -> Result<F, EvssError<PC::Error>> {
+        Self::validate_shares(shares, 1)?;
+        Ok(Self::interpolate(shares.iter(), x0))
+    }
+
+    pub fn reconstruct_polynomial(
+        shares: &[EVSSShare<F, P, PC>],
+    ) -> Result<P, EvssError<PC::Error>> {
+        Self::validate_shares(shares, 1)?;
+        let (points, values): (Vec<F>, Vec<F>) =
+            shares.iter().map(|sh| (sh.point, sh.value)).unzip();
+        Ok(P::from_coefficients_vec(interpolate_polynomial(&points, &values)))
+    }
+
+    pub fn reconstruct_coefficient(
+        shares: &[EVSSShare<F, P, PC>],
+        secret_index: usize,
+    ) -> Result<F, EvssError<PC::Error>> {
+        let poly = Self::reconstruct_polynomial(shares)?;
+        Ok(poly.coeffs().get(secret_index).copied().unwrap_or_else(F::zero))
+    }
+
+    /// First step of handing the secret to a new committee: the holder of `share` deals a
+    /// sub-sharing of its value under `new_params`, which may have a different degree. Each
+    /// new member receives one sub-share from every participating old holder.
+    pub fn reshare_step<R: RngCore>(
+        new_params: &EVSSParams<F, P, PC>,
+        share: &EVSSShare<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        Self::commit(new_params, share.value, rng)
+    }
+
+    /// A new member's share value from its sub-shares, `subshares[i]` having been dealt by the
+    /// old holder at `old_points[i]`. Each sub-share should be checked against its dealer's
+    /// commitment first, and the old points must reach the old threshold. All sub-shares must
+    /// be at the same new point.
+    pub fn combine_subshares(
+        old_points: &[F],
+        subshares: &[EVSSShare<F, P, PC>],
+    ) -> Result<F, EvssError<PC::Error>> {
+        if old_points.len() != subshares.len() {
+            return Err(EvssError::LengthMismatch {
+                expected: old_points.len(),
+                got: subshares.len(),
+            });
+        }
+        if old_points.iter().collect::<BTreeSet<_>>().len() != old_points.len() {
+            return Err(EvssError::DuplicatePoint);
+        }
+        if subshares.windows(2).any(|pair| pair[0].point != pair[1].point) {
+            return Err(EvssError::PointMismatch);
+        }
+        let values: Vec<F> = subshares.iter().map(|sh| sh.value).collect();
+        Ok(Self::weighted_sum(&values, &lagrange_coefficients(old_points)))
+    }
+
+    fn validate_shares(
+        shares: &[EVSSShare<F, P, PC>],
+        need: usize,
+    ) -> Result<(), EvssError<PC::Error>> {
+        let points: Vec<F> = shares.iter().map(|sh| sh.point).collect();
+        validate_points(&points, need)
+    }
+
+    pub fn lagrange_coefficients(points: &[F]) -> Vec<F> {
+        lagrange_coefficients(points)
+    }
+
+    pub fn reconstruct_with_coefficients(values: &[F], coeffs: &[F]) -> Result<F, EvssError<PC::Error>> {
+        if values.len() != coeffs.len() {
+            return Err(EvssError::LengthMismatch {
+                expected: coeffs.len(),
+                got: values.len(),
+            });
+        }
+        Ok(Self::weighted_sum(values, coeffs))
+    }
+
+    // Callers derive `coeffs` from the same points as `values`, so the lengths agree.
+    fn weighted_sum(values: &[F], coeffs: &[F]) -> F {
+        values.iter().zip(coeffs).map(|(&v, &c)| v * c).sum()
+    }
+
+    fn interpolate<'a, I>(shares: I, x0: F) -> F
+    where
+        I: IntoIterator<Item = &'a EVSSShare<F, P, PC>>,
+        P: 'a,
+        PC: 'a,
+    {
+        let (points, values): (Vec<F>, Vec<F>) =
+            shares.into_iter().map(|sh| (sh.point, sh.value)).unzip();
+        Self::weighted_sum(&values, &lagrange_coefficients_at(&points, x0))
+    }
+
+}
+
+impl<F: Field, P: UVPolynomial<F>, PC: HomomorphicPC<F, P>> EVSS<F, P, PC> {
+
+    /// Proactive refresh: adds a fresh sharing of zero to `poly`, giving a new sharing of the
+    /// same secret whose shares cannot be combined with the old ones. Returns the refreshed
+    /// sharing and the zero sharing, whose commitment lets anyone audit the update.
+    #[allow(clippy::type_complexity)]
+    pub fn refresh<R: RngCore>(
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<(EVSSPolynomial<F, P, PC>, EVSSPolynomial<F, P, PC>), EvssError<PC::Error>> {
+        let mask = Self::commit(params, F::zero(), rng)?;
+        Ok((poly.add(&mask), mask))
+    }
+
+    /// Commitment to the sum of the dealers' polynomials, as in a Pedersen DKG.
+    pub fn aggregate_commitments(
+        commits: &[EVSSCommit<F, P, PC>],
+    ) -> Result<EVSSCommit<F, P, PC>, EvssError<PC::Error>> {
+        EVSSCommit::linear_combination(commits, &vec![F::one(); commits.len()])
+    }
+
+    /// `reconstruct` in the group: weights each commitment by the Lagrange coefficient of its
+    /// point at 0. If the commitment at `x_i` commits to a polynomial with constant term
+    /// `g(x_i)`, the result commits to one with constant term `g(0)`. The points must be
+    /// distinct and there must be at least one.
+    pub fn reconstruct_commitment(
+        commit_shares: &[(F, EVSSCommit<F, P, PC>)],
+    ) -> Result<EVSSCommit<F, P, PC>, EvssError<PC::Error>> {
+        if commit_shares.is_empty() {
+            return Err(EvssError::InsufficientShares { have: 0, need: 1 });
+        }
+        if commit_shares.iter().map(|(x, _)| x).collect::<BTreeSet<_>>().len() != commit_shares.len() {
+            return Err(EvssError::DuplicatePoint);
+        }
+        let (points, commits): (Vec<F>, Vec<EVSSCommit<F, P, PC>>) = commit_shares.iter().cloned().unzip();
+        EVSSCommit::linear_combination(&commits, &lagrange_coefficients(&points))
+    }
+
+    /// Folds the shares one participant received from every dealer into a share of the summed
+    /// secret that verifies against `aggregate_commitments`. All shares must be at the same
+    /// point and come from commitments without degree bounds.
+    pub fn aggregate_shares(
+        shares: &[EVSSShare<F, P, PC>],
+    ) -> Result<EVSSShare<F, P, PC>, EvssError<PC::Error>> {
+        let (first, rest) = shares.split_first().ok_or(EvssError::InsufficientShares {
+            have: 0,
+            need: 1,
+        })?;
+        let mut aggregate = first.clone();
+        for sh in rest {
+            if sh.point != aggregate.point {
+                return Err(EvssError::PointMismatch);
+            }
+            aggregate.value += sh.value;
+            aggregate.proof = PC::add_proofs(&aggregate.proof, &sh.proof);
+        }
+        Ok(aggregate)
+    }
+
+    pub fn prove_knowledge<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<KnowledgeProof<F, P, PC>, EvssError<PC::Error>> {
+        let mask: Vec<F> = (0..pp.degree).map(|_| F::rand(rng)).collect();
+        let mask = Self::commit_polynomial(pp, &P::from_coefficients_vec(mask), rng)?;
+        let c = Self::knowledge_challenge(&poly.commit, &mask.commit);
+        let share = Self::open_share(F::zero(), c, DEFAULT_LABEL, None, pp, &mask.add(&poly.scale(c)), rng)?;
+        Ok(KnowledgeProof {
+            mask_commit: mask.commit,
+            value: share.value,
+            proof: share.proof,
+        })
+    }
+
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn verify_knowledge<R: RngCore>(
+        params: &EVSSPublicParams<F, P, PC>,
+        commit: &EVSSCommit<F, P, PC>,
+        proof: &KnowledgeProof<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<bool, EvssError<PC::Error>> {
+        let c = Self::knowledge_challenge(&commit.commit, &proof.mask_commit);
+        let mask = EVSSCommit {
+            commit: proof.mask_commit.clone(),
+        };
+        let combined = EVSSCommit::linear_combination(&[mask, commit.clone()], &[F::one(), c])?;
+        let share = EVSSShare::from_parts(F::zero(), proof.value, c, proof.proof.clone());
+        Self::check(params, &combined, &share, rng)
+    }
+
+    fn knowledge_challenge(commit: &PC::Commitment, mask_commit: &PC::Commitment) -> F {
+        let mut buf: Vec<u8> = Vec::new();
+        commit.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        mask_commit.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        fs_hash(KNOWLEDGE_DOMAIN, &buf)
+    }
+
+}
+
+impl<F: FftField, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSS<F, P, PC> {
+
+    /// Like `get_shares`, but evaluates all points at once: with a single FFT when `points` are
+    /// exactly the elements of a radix-2 domain, otherwise, as for the usual `1..=n`, through a
+    /// subproduct tree whose reductions are FFT multiplications.
+    #[allow(clippy::type_complexity)]
+    pub fn get_shares_fft<R: RngCore>(
+        points: &[F],
+        params: &EVSSParams<F, P, PC>,
+        poly: &EVSSPolynomial<F, P, PC>,
+        rng: &mut R,
+    ) -> Result<Vec<EVSSShare<F, P, PC>>, EvssError<PC::Error>> {
+        let domain = Radix2EvaluationDomain::<F>::new(points.len())
+            .filter(|domain| domain.elements().eq(points.iter().copied()));
+        let values = match domain {
+            Some(domain) => {
+                // Reduce modulo X^n - 1 so polynomials longer than the domain still fit.
+                let mut folded = vec![F::zero(); points.len()];
+                for (i, &c) in poly.polynomial.coeffs().iter().enumerate() {
+                    folded[i % points.len()] += c;
+                }
+                domain.fft(&folded)
+            }
+            _ => multipoint_evaluate(poly.polynomial.coeffs(), points),
+        };
+        Self::open_shares(points, &values, params, poly, rng)
+    }
+
+}
+
+impl<F: PrimeField, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>> EVSS<F, P, PC> {
+
+    /// Maps arbitrary bytes to the secret `F::from_le_bytes_mod_order(Blake2b-512(domain || bytes))`.
+    /// The 512-bit digest keeps the modular bias negligible. The map is one-way: parties agree
+    /// on the resulting field element, not on the original bytes.
+    pub fn secret_from_bytes(secret: &[u8]) -> F {
+        hash_to_field(SECRET_DOMAIN, secret)
+    }
+
+    pub fn commit_bytes<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secret: &[u8],
+        rng: &mut R,
+    ) -> Result<EVSSPolynomial<F, P, PC>, EvssError<PC::Error>> {
+        Self::commit(pp, Self::secret_from_bytes(secret), rng)
+    }
+
+    /// Reconstructs a secret dealt by `commit_bytes` and returns its canonical encoding. This
+    /// is `secret_from_bytes` of the original bytes, not the bytes themselves.
+    pub fn reconstruct_bytes(
+        shares: &[EVSSShare<F, P, PC>],
+    ) -> Result<Vec<u8>, EvssError<PC::Error>> {
+        Self::validate_shares(shares, 1)?;
+        let mut buf: Vec<u8> = Vec::new();
+        Self::reconstruct_slice(shares)
+            .serialize(&mut buf)
+            .expect("serializing into a vector cannot fail");
+        Ok(buf)
+    }
+
+    /// Bytes per chunk in `commit_chunked`: the largest whole number of bytes below the
+    /// modulus, 31 for the scalar fields of the curves defined here.
+    pub fn chunk_size() -> usize {
+        (F::size_in_bits() - 1) / 8
+    }
+
+    /// Shares a secret of any length as one polynomial per `chunk_size()` bytes. The secret is
+    /// padded with a `0x80` byte followed by zeros up to a whole number of chunks, so the
+    /// padding is the last `0x80` and everything after it; the secret itself may end in
+    /// anything. Each chunk is read as a little-endian integer, which is below the modulus.
+    #[allow(clippy::type_complexity)]
+    pub fn commit_chunked<R: RngCore>(
+        pp: &EVSSParams<F, P, PC>,
+        secret: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<EVSSPolynomial<F, P, PC>>, EvssError<PC::Error>> {
+        let size = Self::chunk_size();
+        let mut padded = secret.to_vec();
+        padded.push(0x80);
+        padded.resize(padded.len().div_ceil(size) * size, 0);
+        padded
+            .chunks(size)
+            .map(|chunk| Self::commit(pp, F::from_le_bytes_mod_order(chunk), rng))
+            .collect()
+    }
+
+    /// Inverse of `commit_chunked`: `share_sets[i]` are the shares of chunk `i`.
+    pub fn reconstruct_chunked(
+        share_sets: &[Vec<EVSSShare<F, P, PC>>],
+    ) -> Result<Vec<u8>, EvssError<PC::Error>> {
+        let size = Self::chunk_size();
+        let mut bytes = Vec::with_capacity(share_sets.len() * size);
+        for shares in share_sets {
+            Self::validate_shares(shares, 1)?;
+            let chunk = Self::reconstruct_slice(shares).into_repr().to_bytes_le();
+            if chunk[size..].iter().any(|&b| b != 0) {
+                return Err(EvssError::InvalidPadding);
+            }
+            bytes.extend_from_slice(&chunk[..size]);
+        }
+        match bytes.iter().rposition(|&b| b != 0) {
+            Some(end) if bytes[end] == 0x80 => {
+                bytes.truncate(end);
+                Ok(bytes)
+            }
+            _ => Err(EvssError::InvalidPadding),
+        }
+    }
+
+}
+
+impl<E, F> EVSS<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>
+where
+    E: PairingEngine<Fr = F>,
+    F: PrimeField,
+{
+
+    /// The KZG quotient `q(X) = (f(X) - f(point)) / (X - point)` whose commitment is the
+    /// witness of a non-hiding share at `point`, e.g. as an auxiliary circuit witness.
+    pub fn share_quotient(
+        point: F,
+        poly: &EVSSPolynomial<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>,
+    ) -> DensePolynomial<F> {
+        let (quotient, _) = divide_by_linear(&poly.polynomial.coeffs, point);
+        DensePolynomial::from_coefficients_vec(quotient)
+    }
+
+    /// `check` as one product of two pairings against the prepared `h` and `beta_h`, sharing a
+    /// single final exponentiation: `e(C - v*g - r*gamma_g + z*w, h) * e(-w, beta_h) = 1`.
+    /// Unlike `check`, no G2 point is prepared per share; the `check_prepared` bench measures it
+    /// at roughly half the cost of `check`.
+    /// Commitments with a degree bound are refused with `DegreeBounded`.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check_prepared(
+        params: &PreparedParams<E>,
+        commit: &EVSSCommit<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>,
+        share: &EVSSShare<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>,
+    ) -> Result<bool, EvssError<ark_poly_commit::Error>> {
+        if commit.commit.shifted_comm.is_some() {
+            return Err(EvssError::DegreeBounded);
+        }
+        let vk = &params.public_params.verifier_key.vk;
+        let mut inner = commit.commit.comm.0.into_projective() - vk.g.mul(share.value.into_repr())
+            + share.proof.w.mul(share.point.into_repr());
+        if let Some(random_v) = share.proof.random_v {
+            inner -= &vk.gamma_g.mul(random_v.into_repr());
+        }
+        let pairs = [
+            (inner.into_affine().into(), params.prepared_h.clone()),
+            ((-share.proof.w).into(), params.prepared_beta_h.clone()),
+        ];
+        Ok(E::product_of_pairings(&pairs) == E::Fqk::one())
+    }
+
+    /// `get_shares` with the per-point work of `PC::open` shared: the values come from one
+    /// multipoint evaluation, each witness `(f - f(z)) / (X - z)` and its blinding counterpart
+    /// from one synthetic division, every witness MSM runs against one fixed-base table over
+    /// the committer key (in parallel under `parallel`), and all witnesses are normalized to
+    /// affine with a single inversion. Polynomials committed with a degree bound are refused
+    /// with `DegreeBounded`.
+    #[allow(clippy::type_complexity)]
+    pub fn get_shares_batch<R: RngCore>(
+        points: &[F],
+        params: &EVSSParams<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>,
+        poly: &EVSSPolynomial<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>,
+        rng: &mut R,
+    ) -> Result<Vec<EVSSShare<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>>, EvssError<ark_poly_commit::Error>> {
+        if poly.rands.shifted_rand.is_some() {
+            return Err(EvssError::DegreeBounded);
+        }
+        let ck = &params.committer_key;
+        if poly.polynomial.coeffs.len() > ck.powers.len() {
+            return Err(EvssError::DegreeExceeded {
+                got: poly.polynomial.coeffs.len() - 1,
+                max: ck.powers.len().saturating_sub(1),
+            });
+        }
+        let blinding = &poly.rands.rand.blinding_polynomial;
+        let hiding = poly.rands.rand.is_hiding();
+        let values = multipoint_evaluate(&poly.polynomial.coeffs, points);
+        let msm = FixedBaseMsm::new(&ck.powers[..poly.polynomial.coeffs.len().saturating_sub(1)]);
+        let witnesses: Vec<(E::G1Projective, Option<F>)> = cfg_iter!(points)
+            .map(|&point| {
+                let (quotient, _) = divide_by_linear(&poly.polynomial.coeffs, point);
+                let mut w = msm.mul(&to_bigints(&quotient));
+                let random_v = if hiding {
+                    let (quotient, random_v) = divide_by_linear(&blinding.coeffs, point);
+                    w += &VariableBaseMSM::multi_scalar_mul(&ck.powers_of_gamma_g, &to_bigints(&quotient));
+                    Some(random_v)
+                } else {
+                    None
+                };
+                (w, random_v)
+            })
+            .collect();
+        let (witnesses, random_vs): (Vec<_>, Vec<_>) = witnesses.into_iter().unzip();
+        let witnesses = E::G1Projective::batch_normalization_into_affine(&witnesses);
+        Ok(points
+            .iter()
+            .zip(values)
+            .zip(witnesses.into_iter().zip(random_vs))
+            .map(|((&point, value), (w, random_v))| EVSSShare {
+                point,
+                value,
+                challenge: F::rand(rng),
+                proof: kzg10::Proof { w, random_v },
+            })
+            .collect())
+    }
+
+    /// Verifies one share against each of several commitments, e.g. one share from every
+    /// dealer of a DKG, with a single randomized pairing check. Falls back to individual
+    /// checks if any commitment has a degree bound.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    #[allow(clippy::type_complexity)]
+    pub fn check_multi<R: RngCore>(
+        params: &EVSSPublicParams<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>,
+        pairs: &[(
+            EVSSCommit<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>,
+            EVSSShare<F, DensePolynomial<F>, MarlinKZG10<E, DensePolynomial<F>>>,
+        )],
+        rng: &mut R,
+    ) -> Result<bool, EvssError<ark_poly_commit::Error>> {
+        if pairs.iter().any(|(commit, _)| commit.commit.shifted_comm.is_some()) {
+            for (commit, share) in pairs {
+                if !Self::check(params, commit, share, rng)? {
+                    return Ok(false);
                 }
             }
-            res += term;
+            return Ok(true);
         }
-        res
+        let commits: Vec<_> = pairs.iter().map(|(commit, _)| commit.commit.comm).collect();
+        let points: Vec<F> = pairs.iter().map(|(_, share)| share.point).collect();
+        let values: Vec<F> = pairs.iter().map(|(_, share)| share.value).collect();
+        let proofs: Vec<_> = pairs.iter().map(|(_, share)| share.proof).collect();
+        Ok(KZG10::<E, DensePolynomial<F>>::batch_check(
+            &params.verifier_key.vk,
+            &commits,
+            &points,
+            &values,
+            &proofs,
+            rng,
+        )?)
     }
 
 }
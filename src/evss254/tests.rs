@@ -0,0 +1,24 @@
+use crate::evss254::*;
+
+use ark_std::test_rng;
+
+const DEGREE: usize = 4;
+const INDEX_BEGIN: usize = 1;
+
+#[test]
+fn test_functionality() -> Result<(), EvssError254> {
+    let rng = &mut test_rng();
+    let secret = F254::rand(rng);
+    let params = EVSS254::setup(DEGREE, rng)?;
+    let poly = EVSS254::commit(&params, secret, rng)?;
+    let mut shares = Vec::new();
+    for i in INDEX_BEGIN..INDEX_BEGIN + DEGREE + 1 {
+        shares.push(EVSS254::get_share(F254::from(i as u32), &params, &poly, rng)?);
+    }
+    for sh in &shares {
+        assert!(EVSS254::check(&params.get_public_params(), &poly.get_commit(), sh, rng)?);
+    }
+    assert_eq!(secret, EVSS254::reconstruct(&shares));
+    Ok(())
+}
+
@@ -0,0 +1,43 @@
+use crate::evss377::*;
+
+use ark_std::test_rng;
+
+use serde_json;
+
+const DEGREE: usize = 10;
+const INDEX_BEGIN: usize = 1;
+
+#[test]
+fn test_functionality() -> Result<(), EvssError377> {
+    let rng = &mut test_rng();
+    let secret = F377::rand(rng);
+    let params = EVSS377::setup(DEGREE, rng)?;
+    let poly = EVSS377::commit(&params, secret, rng)?;
+    let mut shares = Vec::new();
+    for i in INDEX_BEGIN..INDEX_BEGIN + DEGREE + 1 {
+        shares.push(EVSS377::get_share(F377::from(i as u32), &params, &poly, rng)?);
+    }
+    for sh in &shares {
+        assert!(EVSS377::check(&params.get_public_params(), &poly.get_commit(), sh, rng)?);
+    }
+    assert_eq!(secret, EVSS377::reconstruct(&shares));
+    Ok(())
+}
+
+#[test]
+fn test_serde() -> Result<(), serde_json::Error> {
+    let rng = &mut test_rng();
+    let secret = F377::rand(rng);
+    let params = EVSS377::setup(DEGREE, rng).expect("");
+    let poly = EVSS377::commit(&params, secret, rng).expect("");
+    let _: EVSSParams377 = serde_json::from_str(&serde_json::to_string(&params)?)?;
+    let _: EVSSPublicParams377 =
+        serde_json::from_str(&serde_json::to_string(&params.get_public_params())?)?;
+    for i in INDEX_BEGIN..INDEX_BEGIN + DEGREE + 1 {
+        let _: EVSSShare377 = serde_json::from_str(&serde_json::to_string(
+            &EVSS377::get_share(F377::from(i as u32), &params, &poly, rng).unwrap(),
+        )?)?;
+    }
+    Ok(())
+}
+
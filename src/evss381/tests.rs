@@ -0,0 +1,1643 @@
+use crate::evss381::*;
+use crate::error::EvssError;
+use crate::transcript::{Blake2bTranscript, EvssTranscript};
+
+use ark_ec::AffineCurve;
+use ark_ff::{PrimeField, Zero};
+use ark_poly::{EvaluationDomain, Polynomial, Radix2EvaluationDomain, UVPolynomial};
+
+use ark_std::test_rng;
+
+use bincode;
+use proptest::prelude::*;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+use serde_json;
+
+const DEGREE: usize = 10;
+const INDEX_BEGIN: usize = 1;
+
+/// What most tests start from: a commitment to a random secret under `setup(DEGREE)`, and
+/// the `DEGREE + 1` share points from `INDEX_BEGIN`.
+struct Fixture {
+    secret: F381,
+    params: EVSSParams381,
+    poly: EVSSPolynomial381,
+    points: Vec<F381>,
+}
+
+fn fixture<R: RngCore>(rng: &mut R) -> Result<Fixture, EvssError381> {
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, secret, rng)?;
+    let points = share_points(DEGREE + 1);
+    Ok(Fixture { secret, params, poly, points })
+}
+
+/// `n` consecutive share points from `INDEX_BEGIN`.
+fn share_points(n: usize) -> Vec<F381> {
+    (INDEX_BEGIN..INDEX_BEGIN + n).map(|i| F381::from(i as u32)).collect()
+}
+
+#[test]
+fn test_functionality() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, secret, rng)?;
+    let mut shares = Vec::new();
+    for i in INDEX_BEGIN..INDEX_BEGIN + DEGREE + 1 {
+        shares.push(EVSS381::get_share(F381::from(i as u32), &params, &poly, rng)?);
+    }
+    for sh in &shares {
+        assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), sh, rng)?);
+    }
+    assert_eq!(secret, EVSS381::reconstruct(&shares));
+    Ok(())
+}
+
+#[test]
+fn test_reconstruct_slice() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, points } = fixture(rng)?;
+    let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    assert_eq!(secret, EVSS381::reconstruct_slice(&shares));
+    assert_eq!(EVSS381::reconstruct(&shares[1..].to_vec()), EVSS381::reconstruct_slice(&shares[1..]));
+    Ok(())
+}
+
+#[test]
+fn test_get_shares() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, mut points } = fixture(rng)?;
+    points.reverse();
+    let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    assert_eq!(shares.len(), points.len());
+    for (sh, point) in shares.iter().zip(&points) {
+        assert_eq!(sh.point, *point);
+        assert_eq!(sh.value, poly.evaluate(*point));
+        assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), sh, rng)?);
+    }
+    assert_eq!(secret, EVSS381::reconstruct(&shares));
+    assert!(EVSS381::get_shares(&[], &params, &poly, rng)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_try_reconstruct() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, points } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let mut shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    assert_eq!(secret, EVSS381::try_reconstruct(&public_params, &shares)?);
+    assert!(matches!(
+        EVSS381::try_reconstruct(&public_params, &shares[1..]),
+        Err(EvssError::InsufficientShares { have, need }) if have == DEGREE && need == DEGREE + 1
+    ));
+    shares[0] = shares[1].clone();
+    assert!(matches!(
+        EVSS381::try_reconstruct(&public_params, &shares),
+        Err(EvssError::DuplicatePoint)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_reconstruct_single_share() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, .. } = fixture(rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    assert!(matches!(
+        EVSS381::try_reconstruct(&params.get_public_params(), std::slice::from_ref(&share)),
+        Err(EvssError::InsufficientShares { have: 1, need }) if need == DEGREE + 1
+    ));
+    assert!(matches!(
+        EVSS381::reconstruct_with_threshold(&[share], 1),
+        Err(EvssError::InsufficientShares { have: 1, need: 2 })
+    ));
+    let constant = EVSS381::commit_polynomial(&params, &Poly381::from_coefficients_vec(vec![secret]), rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &params, &constant, rng)?;
+    assert_eq!(secret, EVSS381::reconstruct_with_threshold(&[share], 0)?);
+    Ok(())
+}
+
+#[test]
+fn test_reconstruct_weighted() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, poly, points, .. } = fixture(rng)?;
+    let weights = [4, 1, 3, 2, 1];
+    let pairs: Vec<(F381, F381)> = points
+        .into_iter()
+        .map(|point| (point, poly.evaluate(point)))
+        .collect();
+    assert_eq!(secret, EVSS381::reconstruct_weighted(&pairs, &weights, DEGREE)?);
+    assert!(matches!(
+        EVSS381::reconstruct_weighted(&pairs[4..], &weights[1..], DEGREE),
+        Err(EvssError::InsufficientShares { have, need }) if have == DEGREE - 3 && need == DEGREE + 1
+    ));
+    assert!(matches!(
+        EVSS381::reconstruct_weighted(&pairs, &weights[1..], DEGREE),
+        Err(EvssError::LengthMismatch { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_can_reconstruct() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, poly, points, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    assert_eq!(DEGREE + 1, public_params.threshold());
+    let mut shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    assert!(EVSS381::can_reconstruct(&public_params, &shares));
+    assert!(!EVSS381::can_reconstruct(&public_params, &shares[1..]));
+    shares[0] = shares[1].clone();
+    assert!(!EVSS381::can_reconstruct(&public_params, &shares));
+    Ok(())
+}
+
+#[test]
+fn test_reconstruct_with_threshold() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, .. } = fixture(rng)?;
+    let points = share_points(2 * DEGREE + 1);
+    let mut shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    shares[DEGREE + 1] = shares[0].clone();
+    assert_eq!(secret, EVSS381::reconstruct_with_threshold(&shares, DEGREE)?);
+    assert!(matches!(
+        EVSS381::reconstruct_with_threshold(&shares[..DEGREE], DEGREE),
+        Err(EvssError::InsufficientShares { have, need }) if have == DEGREE && need == DEGREE + 1
+    ));
+    shares[DEGREE] = shares[0].clone();
+    assert_eq!(secret, EVSS381::reconstruct_with_threshold(&shares, DEGREE)?);
+    let repeated = [shares[0].clone(), shares[0].clone(), shares[1].clone(), shares[2].clone()];
+    assert!(matches!(
+        EVSS381::reconstruct_with_threshold(&repeated, DEGREE),
+        Err(EvssError::InsufficientShares { have: 3, need }) if need == DEGREE + 1
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_lagrange_coefficients() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, points, .. } = fixture(rng)?;
+    let coeffs = EVSS381::lagrange_coefficients(&points);
+    for _ in 0..3 {
+        let secret = F381::rand(rng);
+        let poly = EVSS381::commit(&params, secret, rng)?;
+        let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+        let values: Vec<F381> = shares.iter().map(|sh| sh.value).collect();
+        assert_eq!(secret, EVSS381::reconstruct_with_coefficients(&values, &coeffs)?);
+    }
+    assert!(matches!(
+        EVSS381::reconstruct_with_coefficients(&coeffs[1..], &coeffs),
+        Err(EvssError::LengthMismatch { expected, got }) if expected == DEGREE + 1 && got == DEGREE
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_reconstruct_polynomial() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, points } = fixture(rng)?;
+    let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    assert_eq!(poly.polynomial.clone(), EVSS381::reconstruct_polynomial(&shares)?);
+    let x0 = F381::rand(rng);
+    let recovered = EVSS381::reconstruct_polynomial(&shares).unwrap();
+    assert_eq!(recovered.evaluate(&x0), EVSS381::reconstruct_at(&shares, x0)?);
+    assert_eq!(secret, EVSS381::reconstruct_at(&shares, F381::zero())?);
+    assert!(matches!(
+        EVSS381::reconstruct_polynomial(&[]),
+        Err(EvssError::InsufficientShares { have: 0, need: 1 })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_commit_at() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, points, .. } = fixture(rng)?;
+    let poly = EVSS381::commit_at(&params, secret, DEGREE - 1, rng)?;
+    let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    for sh in &shares {
+        assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), sh, rng)?);
+    }
+    assert_eq!(secret, EVSS381::reconstruct_coefficient(&shares, DEGREE - 1)?);
+    assert!(EVSS381::commit_at(&params, secret, DEGREE, rng).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_labeled() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, points, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let poly = EVSS381::commit_labeled(&params, secret, "dealer", rng)?;
+    let mut shares = Vec::new();
+    for &point in &points {
+        let share = EVSS381::get_share_labeled(point, &params, &poly, "dealer", rng)?;
+        assert!(EVSS381::check_labeled(&public_params, &poly.get_commit(), &share, "dealer", rng)?);
+        shares.push(share);
+    }
+    assert_eq!(secret, EVSS381::reconstruct(&shares));
+    Ok(())
+}
+
+#[test]
+fn test_commit_deterministic() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly1 = EVSS381::commit_deterministic(&params, secret, [7u8; 32])?;
+    let poly2 = EVSS381::commit_deterministic(&params, secret, [7u8; 32])?;
+    let poly3 = EVSS381::commit_deterministic(&params, secret, [8u8; 32])?;
+    assert_eq!(poly1.polynomial, poly2.polynomial);
+    assert_eq!(poly1.commit, poly2.commit);
+    assert_ne!(poly1.polynomial, poly3.polynomial);
+    let share = EVSS381::get_share(F381::from(INDEX_BEGIN as u32), &params, &poly1, rng)?;
+    assert!(EVSS381::check(&params.get_public_params(), &poly2.get_commit(), &share, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_commit_polynomial() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = Poly381::rand(DEGREE, rng);
+    let committed = EVSS381::commit_polynomial(&params, &poly, rng)?;
+    assert_eq!(poly, committed.polynomial);
+    let share = EVSS381::get_share(F381::from(INDEX_BEGIN as u32), &params, &committed, rng)?;
+    assert!(EVSS381::check(&params.get_public_params(), &committed.get_commit(), &share, rng)?);
+    assert!(EVSS381::commit_polynomial(&params, &Poly381::rand(DEGREE + 1, rng), rng).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_fiat_shamir() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, poly, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let share = EVSS381::get_share_fs(F381::from(INDEX_BEGIN as u32), &params, &poly, rng)?;
+    assert!(EVSS381::check_fs(&public_params, &poly.get_commit(), &share, rng)?);
+    let mut forged = share.clone();
+    forged.challenge = F381::rand(rng);
+    assert!(EVSS381::check_fs(&public_params, &poly.get_commit(), &forged, rng)?);
+    forged.value += F381::from(1u32);
+    assert!(!EVSS381::check_fs(&public_params, &poly.get_commit(), &forged, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_setup_degree_zero() {
+    let rng = &mut test_rng();
+    assert!(matches!(EVSS381::setup(0, rng), Err(EvssError::DegreeIsZero)));
+}
+
+#[test]
+fn test_serde() -> Result<(), serde_json::Error> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng).expect("");
+    let poly = EVSS381::commit(&params, secret, rng).expect("");
+    let _: EVSSParams381 = serde_json::from_str(&serde_json::to_string(&params)?)?;
+    let _: EVSSPublicParams381 =
+        serde_json::from_str(&serde_json::to_string(&params.get_public_params())?)?;
+    for i in INDEX_BEGIN..INDEX_BEGIN + DEGREE + 1 {
+        println!(
+            "{}",
+            serde_json::to_string(
+                &EVSS381::get_share(F381::from(i as u32), &params, &poly, rng).unwrap()
+            )?
+        );
+        let _: EVSSShare381 = serde_json::from_str(&serde_json::to_string(
+            &EVSS381::get_share(F381::from(i as u32), &params, &poly, rng).unwrap(),
+        )?)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bincode() -> Result<(), bincode::Error> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng).expect("");
+    let poly = EVSS381::commit(&params, secret, rng).expect("");
+    let share = EVSS381::get_share(F381::from(INDEX_BEGIN as u32), &params, &poly, rng).expect("");
+    let public_params: EVSSPublicParams381 =
+        bincode::deserialize(&bincode::serialize(&params.get_public_params())?)?;
+    let commit: EVSSCommit381 = bincode::deserialize(&bincode::serialize(&poly.get_commit())?)?;
+    let encoded = bincode::serialize(&share)?;
+    assert!(encoded.len() < serde_json::to_string(&share).expect("").len());
+    let decoded: EVSSShare381 = bincode::deserialize(&encoded)?;
+    assert_eq!(share.point, decoded.point);
+    assert_eq!(share.value, decoded.value);
+    assert_eq!(share.challenge, decoded.challenge);
+    assert!(EVSS381::check(&public_params, &commit, &decoded, rng).expect(""));
+    Ok(())
+}
+
+#[test]
+fn test_compressed_encoding() -> Result<(), bincode::Error> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng).expect("");
+    let commit = EVSS381::commit(&params, secret, rng).expect("").get_commit();
+    assert!(commit.commit.serialized_size() < commit.commit.uncompressed_size());
+    assert_eq!(bincode::serialize(&commit)?.len(), 8 + commit.commit.serialized_size());
+    Ok(())
+}
+
+#[test]
+fn test_hex_serde() -> Result<(), serde_json::Error> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng).expect("");
+    let commit = EVSS381::commit(&params, secret, rng).expect("").get_commit();
+    let mut bytes = Vec::new();
+    commit.commit.serialize(&mut bytes).expect("");
+    let json = serde_json::to_string(&commit)?;
+    assert_eq!(json, format!("{{\"commit\":\"{}\"}}", hex::encode(&bytes)));
+    let decoded: EVSSCommit381 = serde_json::from_str(&json)?;
+    assert_eq!(commit.commit, decoded.commit);
+    let legacy: EVSSCommit381 = serde_json::from_str(&format!("{{\"commit\":{:?}}}", bytes))?;
+    assert_eq!(commit.commit, legacy.commit);
+    Ok(())
+}
+
+#[test]
+fn test_serialized_size() -> Result<(), bincode::Error> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng).expect("");
+    let poly = EVSS381::commit(&params, secret, rng).expect("");
+    let share = EVSS381::get_share(F381::from(INDEX_BEGIN as u32), &params, &poly, rng).expect("");
+    // bincode prefixes every byte field with a u64 length.
+    assert_eq!(bincode::serialize(&params)?.len(), params.serialized_size() + 2 * 8);
+    assert_eq!(
+        bincode::serialize(&params.get_public_params())?.len(),
+        params.get_public_params().serialized_size() + 8
+    );
+    assert_eq!(bincode::serialize(&poly.get_commit())?.len(), poly.get_commit().serialized_size() + 8);
+    assert_eq!(bincode::serialize(&share)?.len(), share.serialized_size() + 4 * 8);
+    Ok(())
+}
+
+#[test]
+fn test_get_shares_fft() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, poly, points: integer_points, .. } = fixture(rng)?;
+    let domain_points: Vec<F381> = Radix2EvaluationDomain::<F381>::new(8).unwrap().elements().collect();
+    for points in &[domain_points, integer_points] {
+        let shares = EVSS381::get_shares(points, &params, &poly, &mut rng.clone())?;
+        let shares_fft = EVSS381::get_shares_fft(points, &params, &poly, &mut rng.clone())?;
+        for (sh, sh_fft) in shares.iter().zip(&shares_fft) {
+            assert_eq!(bincode::serialize(sh).unwrap(), bincode::serialize(sh_fft).unwrap());
+            assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), sh_fft, rng)?);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_get_shares_fft_subproduct_tree() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let points: Vec<F381> = (1..=100u32).map(F381::from).collect();
+    for &degree in &[150, 99, 40] {
+        let poly = Poly381::rand(degree, rng);
+        let values = crate::helper::multipoint_evaluate(&poly.coeffs, &points);
+        assert_eq!(values, points.iter().map(|&p| poly.evaluate(&p)).collect::<Vec<_>>());
+    }
+    let degree = 40;
+    let params = EVSS381::setup(degree, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let shares = EVSS381::get_shares_fft(&points[..=degree], &params, &poly, rng)?;
+    for (sh, &point) in shares.iter().zip(&points) {
+        assert_eq!(sh.value, poly.evaluate(point));
+        assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), sh, rng)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_get_shares_batch() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, points, .. } = fixture(rng)?;
+    let params = EVSS381::setup_with_hiding_bound(DEGREE, 1, rng)?;
+    let public_params = params.get_public_params();
+    for hiding_bound in [None, Some(1)] {
+        let poly = EVSS381::commit_with_hiding_bound(&params, secret, hiding_bound, rng)?;
+        let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+        let batch = EVSS381::get_shares_batch(&points, &params, &poly, rng)?;
+        assert_eq!(shares.len(), batch.len());
+        for (sh, sh_batch) in shares.iter().zip(&batch) {
+            assert_eq!((sh.point, sh.value), (sh_batch.point, sh_batch.value));
+            assert_eq!(sh.proof, sh_batch.proof);
+            assert!(EVSS381::check(&public_params, &poly.get_commit(), sh_batch, rng)?);
+        }
+        assert_eq!(secret, EVSS381::reconstruct(&batch));
+    }
+    let small = EVSS381::setup(DEGREE / 2, rng)?;
+    let poly = EVSS381::commit(&params, secret, rng)?;
+    assert!(matches!(
+        EVSS381::get_shares_batch(&points, &small, &poly, rng),
+        Err(EvssError::DegreeExceeded { .. })
+    ));
+    let bound = DEGREE / 2;
+    let params = EVSS381::setup_with_degree_bounds(DEGREE, &[bound], rng)?;
+    let poly = EVSS381::commit_bounded(&params, secret, bound, rng)?;
+    assert!(matches!(
+        EVSS381::get_shares_batch(&points, &params, &poly, rng),
+        Err(EvssError::DegreeBounded)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_prepared_params() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, points, .. } = fixture(rng)?;
+    let params = EVSS381::setup_with_hiding_bound(DEGREE, 1, rng)?;
+    let prepared = PreparedParams381::new(params.get_public_params());
+    for hiding_bound in [None, Some(1)] {
+        let poly = EVSS381::commit_with_hiding_bound(&params, secret, hiding_bound, rng)?;
+        let commit = poly.get_commit();
+        let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+        for sh in &shares {
+            assert!(EVSS381::check_prepared(&prepared, &commit, sh)?);
+        }
+        let mut forged = shares[0].clone();
+        forged.value += F381::from(1u32);
+        assert!(!EVSS381::check_prepared(&prepared, &commit, &forged)?);
+        assert_eq!(secret, EVSS381::reconstruct(&shares));
+    }
+    let bound = DEGREE / 2;
+    let params = EVSS381::setup_with_degree_bounds(DEGREE, &[bound], rng)?;
+    let poly = EVSS381::commit_bounded(&params, secret, bound, rng)?;
+    let share = EVSS381::get_share_bounded(points[0], &params, &poly, bound, rng)?;
+    assert!(matches!(
+        EVSS381::check_prepared(&PreparedParams381::new(params.get_public_params()), &poly.get_commit(), &share),
+        Err(EvssError::DegreeBounded)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_add() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret: a, params, poly, points } = fixture(rng)?;
+    let b = F381::rand(rng);
+    let sum = poly.add(&EVSS381::commit(&params, b, rng)?);
+    let shares = EVSS381::get_shares(&points, &params, &sum, rng)?;
+    for sh in &shares {
+        assert!(EVSS381::check(&params.get_public_params(), &sum.get_commit(), sh, rng)?);
+    }
+    assert_eq!(a + b, EVSS381::reconstruct(&shares));
+    Ok(())
+}
+
+#[test]
+fn test_scale() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, points } = fixture(rng)?;
+    let k = F381::rand(rng);
+    let scaled = poly.scale(k);
+    let shares = EVSS381::get_shares(&points, &params, &scaled, rng)?;
+    for sh in &shares {
+        assert!(EVSS381::check(&params.get_public_params(), &scaled.get_commit(), sh, rng)?);
+    }
+    assert_eq!(k * secret, EVSS381::reconstruct(&shares));
+    Ok(())
+}
+
+#[test]
+fn test_linear_combination() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let secrets: Vec<F381> = (0..3).map(|_| F381::rand(rng)).collect();
+    let coeffs: Vec<F381> = (0..3).map(|_| F381::rand(rng)).collect();
+    let mut polys = Vec::new();
+    for &secret in &secrets {
+        polys.push(EVSS381::commit(&params, secret, rng)?);
+    }
+    let combined = polys[0]
+        .scale(coeffs[0])
+        .add(&polys[1].scale(coeffs[1]))
+        .add(&polys[2].scale(coeffs[2]));
+    let commits: Vec<EVSSCommit381> = polys.iter().map(|p| p.get_commit()).collect();
+    let commit = EVSSCommit381::linear_combination(&commits, &coeffs)?;
+    assert_eq!(combined.commit, commit.commit);
+    assert!(matches!(
+        EVSSCommit381::linear_combination(&commits, &coeffs[1..]),
+        Err(EvssError::LengthMismatch { expected: 2, got: 3 })
+    ));
+    let share = EVSS381::get_share(F381::from(INDEX_BEGIN as u32), &params, &combined, rng)?;
+    assert!(EVSS381::check(&params.get_public_params(), &commit, &share, rng)?);
+    Ok(())
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize() -> Result<(), EvssError381> {
+    use zeroize::{Zeroize, Zeroizing};
+
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let mut poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    poly.zeroize();
+    assert!(poly.polynomial.coeffs.is_empty());
+    let poly = Zeroizing::new(EVSS381::commit(&params, F381::rand(rng), rng)?);
+    let share = EVSS381::get_share(F381::from(INDEX_BEGIN as u32), &params, &poly, rng)?;
+    assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), &share, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_dealer() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, points, .. } = fixture(rng)?;
+    let mut transcripts = Vec::new();
+    for _ in 0..2 {
+        let mut dealer = Dealer381::<rand_chacha::ChaCha20Rng>::new_from_seed(params.clone(), [3u8; 32]);
+        let poly = dealer.commit(secret)?;
+        let shares = dealer.get_shares(&points, &poly)?;
+        for sh in &shares {
+            assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), sh, rng)?);
+        }
+        assert_eq!(secret, EVSS381::reconstruct(&shares));
+        transcripts.push(bincode::serialize(&(poly.get_commit(), shares)).unwrap());
+    }
+    assert_eq!(transcripts[0], transcripts[1]);
+    Ok(())
+}
+
+#[test]
+fn test_check_at_indices() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, poly, points: allowed, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let share = EVSS381::get_share(allowed[0], &params, &poly, rng)?;
+    assert!(EVSS381::check_at_indices(&public_params, &poly.get_commit(), &share, &allowed, rng)?);
+    let share = EVSS381::get_share(F381::from((INDEX_BEGIN + DEGREE + 1) as u32), &params, &poly, rng)?;
+    assert!(EVSS381::check(&public_params, &poly.get_commit(), &share, rng)?);
+    assert!(!EVSS381::check_at_indices(&public_params, &poly.get_commit(), &share, &allowed, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_polynomial_degree() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, .. } = fixture(rng)?;
+    let points = share_points(poly.degree() + 1);
+    let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    assert_eq!(secret, EVSS381::reconstruct(&shares));
+    assert_ne!(secret, EVSS381::reconstruct(&shares[1..].to_vec()));
+    Ok(())
+}
+
+#[test]
+fn test_commit_bounded() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let bound = DEGREE / 2;
+    let params = EVSS381::setup_with_degree_bounds(DEGREE, &[bound], rng)?;
+    let public_params = params.get_public_params();
+    let poly = EVSS381::commit_bounded(&params, secret, bound, rng)?;
+    assert_eq!(poly.degree(), bound);
+    let mut shares = Vec::new();
+    for &point in &share_points(bound + 1) {
+        shares.push(EVSS381::get_share_bounded(point, &params, &poly, bound, rng)?);
+    }
+    for sh in &shares {
+        assert!(EVSS381::check_bounded(&public_params, &poly.get_commit(), sh, bound, rng)?);
+    }
+    assert_eq!(secret, EVSS381::reconstruct(&shares));
+    let unbounded = EVSS381::commit(&params, secret, rng)?;
+    let share = EVSS381::get_share(F381::from(INDEX_BEGIN as u32), &params, &unbounded, rng)?;
+    assert!(!EVSS381::check_bounded(&public_params, &unbounded.get_commit(), &share, bound, rng)?);
+    assert!(EVSS381::commit_bounded(&params, secret, bound + 1, rng).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_check_bounded_forged_challenge() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let bound = DEGREE / 2;
+    let params = EVSS381::setup_with_degree_bounds(DEGREE, &[bound], rng)?;
+    let public_params = params.get_public_params();
+    let over = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    assert!(over.degree() > bound);
+    // A zero challenge drops the shifted commitment, so any shifted part passes.
+    let mut forged = over.get_commit();
+    forged.commit.shifted_comm = Some(forged.commit.comm);
+    let point = F381::from(INDEX_BEGIN as u32);
+    let share = EVSS381::get_share_with_challenge(point, &params, &over, F381::zero(), rng)?;
+    assert!(!EVSS381::check_bounded(&public_params, &forged, &share, bound, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_check_degree() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, poly, points, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    let commit = poly.get_commit();
+    assert!(EVSS381::check_degree(&public_params, &commit, &shares, DEGREE - 1, rng)?);
+    assert!(!EVSS381::check_degree(&public_params, &commit, &shares, DEGREE / 2, rng)?);
+    assert!(matches!(
+        EVSS381::check_degree(&public_params, &commit, &shares[1..], DEGREE - 1, rng),
+        Err(EvssError::InsufficientShares { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_reconstruct_ct() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, points } = fixture(rng)?;
+    let mut shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    assert_eq!(secret, EVSS381::reconstruct_ct(&shares)?);
+    shares[0] = shares[1].clone();
+    assert!(matches!(EVSS381::reconstruct_ct(&shares), Err(EvssError::DuplicatePoint)));
+    Ok(())
+}
+
+#[test]
+fn test_feldman() -> Result<(), serde_json::Error> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let poly = Feldman381::commit(DEGREE, secret, rng);
+    let commit: FeldmanCommit381 = serde_json::from_str(&serde_json::to_string(&poly.get_commit())?)?;
+    let mut shares = Vec::new();
+    for &point in &share_points(DEGREE) {
+        let share = Feldman381::get_share(point, &poly);
+        assert!(Feldman381::check(&commit, &share));
+        shares.push(share);
+    }
+    let reconstruct = |shares: &[FeldmanShare381]| -> Result<F381, EvssError381> { Feldman381::reconstruct(DEGREE, shares) };
+    assert_eq!(secret, reconstruct(&shares).unwrap());
+    assert!(matches!(
+        reconstruct(&shares[1..]),
+        Err(EvssError::InsufficientShares { have, need: DEGREE }) if have == DEGREE - 1
+    ));
+    let mut repeated = shares.clone();
+    repeated[1] = repeated[0].clone();
+    assert!(matches!(reconstruct(&repeated), Err(EvssError::DuplicatePoint)));
+    shares[0].value += F381::from(1u32);
+    assert!(!Feldman381::check(&commit, &shares[0]));
+    Ok(())
+}
+
+#[test]
+fn test_shamir() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let points = share_points(DEGREE);
+    let shares = ShamirSharing381::share(DEGREE, secret, &points, rng);
+    let shares: Vec<ShamirShare381> = serde_json::from_str(&serde_json::to_string(&shares).unwrap()).unwrap();
+    let reconstruct = |degree, shares: &[ShamirShare381]| -> Result<F381, EvssError381> { ShamirSharing381::reconstruct(degree, shares) };
+    assert_eq!(secret, reconstruct(DEGREE, &shares)?);
+    assert_ne!(secret, reconstruct(DEGREE - 1, &shares[1..])?);
+    assert!(matches!(
+        reconstruct(DEGREE, &shares[1..]),
+        Err(EvssError::InsufficientShares { have, need: DEGREE }) if have == DEGREE - 1
+    ));
+    let mut repeated = shares.clone();
+    repeated[1] = repeated[0].clone();
+    assert!(matches!(reconstruct(DEGREE, &repeated), Err(EvssError::DuplicatePoint)));
+    Ok(())
+}
+
+#[test]
+fn test_hiding_share() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSSHiding381::setup(DEGREE, rng)?;
+    let public_params = params.get_public_params();
+    let poly = EVSSHiding381::commit(&params, secret, rng)?;
+    let mut shares = Vec::new();
+    let mut blinding = F381::zero();
+    let points = share_points(DEGREE);
+    for (&point, lambda) in points.iter().zip(EVSS381::lagrange_coefficients(&points)) {
+        let (share, opening) = EVSSHiding381::get_share(point, &params, &poly, rng)?;
+        assert_eq!(opening.value, poly.evaluate(point));
+        assert_ne!(opening.blinding, F381::zero());
+        assert!(EVSSHiding381::check(&public_params, &poly.get_commit(), &share));
+        assert!(EVSSHiding381::verify_opening(&public_params, &share, &opening));
+        blinding += lambda * opening.blinding;
+        shares.push(share);
+    }
+    let opening = EVSSHidingOpening381 { value: secret, blinding };
+    assert_eq!(EVSSHiding381::reconstruct(&shares)?, EVSSHiding381::pedersen(&public_params, &opening));
+    assert!(matches!(
+        EVSSHiding381::reconstruct(&[]),
+        Err(EvssError::InsufficientShares { have: 0, need: 1 })
+    ));
+    let mut repeated = shares.clone();
+    repeated[1] = repeated[0].clone();
+    assert!(matches!(EVSSHiding381::reconstruct(&repeated), Err(EvssError::DuplicatePoint)));
+    let (mut forged, _) = EVSSHiding381::get_share(points[0], &params, &poly, rng)?;
+    forged.value_commit = shares[1].value_commit;
+    assert!(!EVSSHiding381::check(&public_params, &poly.get_commit(), &forged));
+    assert!(matches!(
+        EVSSHiding381::commit(&EVSS381::setup(DEGREE, rng)?, secret, rng),
+        Err(EvssError::HidingBoundExceeded { got: DEGREE, max: 0 })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_share_parts() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, poly, .. } = fixture(rng)?;
+    let share = EVSS381::get_share(F381::from(INDEX_BEGIN as u32), &params, &poly, rng)?;
+    let (point, value, challenge, proof) = share.clone().into_proof_parts();
+    assert_eq!((point, value, challenge), (share.point, share.value, share.challenge));
+    let rebuilt = EVSSShare381::from_parts(point, value, challenge, proof);
+    assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), &rebuilt, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_get_share_checked() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, poly, .. } = fixture(rng)?;
+    let used = [F381::from(INDEX_BEGIN as u32)];
+    assert!(matches!(
+        EVSS381::get_share_checked(F381::zero(), &used, &params, &poly, rng),
+        Err(EvssError::ZeroPoint)
+    ));
+    assert!(matches!(
+        EVSS381::get_share_checked(used[0], &used, &params, &poly, rng),
+        Err(EvssError::DuplicatePoint)
+    ));
+    let share = EVSS381::get_share_checked(F381::from((INDEX_BEGIN + 1) as u32), &used, &params, &poly, rng)?;
+    assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), &share, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_commit_packed() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let secrets: Vec<F381> = (0..3).map(|_| F381::rand(rng)).collect();
+    let secret_points: Vec<F381> = (0..3).map(|i| -F381::from(i as u32)).collect();
+    let poly = EVSS381::commit_packed(&params, &secrets, &secret_points, rng)?;
+    assert_eq!(poly.degree(), DEGREE - 1);
+    let points = share_points(DEGREE);
+    let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    for sh in &shares {
+        assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), sh, rng)?);
+    }
+    assert_eq!(secrets, EVSS381::reconstruct_packed(&shares, &secret_points)?);
+    assert!(matches!(
+        EVSS381::commit_packed(&params, &secrets, &secret_points[..2], rng),
+        Err(EvssError::LengthMismatch { expected: 3, got: 2 })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_knowledge_proof() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let proof = EVSS381::prove_knowledge(&params, &poly, rng)?;
+    assert!(EVSS381::verify_knowledge(&public_params, &poly.get_commit(), &proof, rng)?);
+    assert_ne!(proof.value, secret);
+    let other = EVSS381::commit(&params, secret, rng)?;
+    assert!(!EVSS381::verify_knowledge(&public_params, &other.get_commit(), &proof, rng)?);
+    let mut forged = proof.clone();
+    forged.value += F381::from(1u32);
+    assert!(!EVSS381::verify_knowledge(&public_params, &poly.get_commit(), &forged, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_reconstruct_verified() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let points = share_points(DEGREE + 2);
+    let mut shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    shares[0].value += F381::from(1u32);
+    assert_eq!(secret, EVSS381::reconstruct_verified(&public_params, &poly.get_commit(), &shares, rng)?);
+    shares[1].value += F381::from(1u32);
+    assert!(matches!(
+        EVSS381::reconstruct_verified(&public_params, &poly.get_commit(), &shares, rng),
+        Err(EvssError::InsufficientShares { have, need }) if have == DEGREE && need == DEGREE + 1
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_reconstruct_correcting() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, .. } = fixture(rng)?;
+    let threshold = DEGREE - 1;
+    let max_errors = 2;
+    let points = share_points(threshold + 1 + 2 * max_errors);
+    let mut shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    assert_eq!((secret, vec![]), EVSS381::reconstruct_correcting(&shares, threshold, max_errors)?);
+    shares[1].value += F381::from(1u32);
+    shares[4].value = F381::rand(rng);
+    assert_eq!((secret, vec![1, 4]), EVSS381::reconstruct_correcting(&shares, threshold, max_errors)?);
+    shares[2].value += F381::from(1u32);
+    assert!(!matches!(
+        EVSS381::reconstruct_correcting(&shares, threshold, max_errors),
+        Ok((s, _)) if s == secret
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_reconstructor() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, points } = fixture(rng)?;
+    let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    let mut reconstructor = Reconstructor381::new(&points)?;
+    for sh in shares.iter().rev() {
+        assert!(!reconstructor.is_complete());
+        reconstructor.add_share(sh)?;
+    }
+    assert!(matches!(reconstructor.add_share(&shares[0]), Err(EvssError::DuplicatePoint)));
+    assert!(reconstructor.is_complete());
+    assert_eq!(secret, reconstructor.finish());
+    let other = EVSS381::get_share(F381::from(100u32), &params, &poly, rng)?;
+    assert!(matches!(
+        Reconstructor381::new(&points)?.add_share(&other),
+        Err(EvssError::UnknownPoint)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_commit_many() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let secrets: Vec<F381> = (0..4).map(|_| F381::rand(rng)).collect();
+    let polys = EVSS381::commit_many(&params, &secrets, rng)?;
+    assert_eq!(secrets.len(), polys.len());
+    for (secret, poly) in secrets.iter().zip(&polys) {
+        assert_eq!(*secret, poly.evaluate(F381::zero()));
+        let share = EVSS381::get_share(F381::from(1u32), &params, poly, rng)?;
+        assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), &share, rng)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_participant_index() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, poly, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    assert!(ParticipantIndex::from_u64(0).is_none());
+    let first = ParticipantIndex::from_u64(1).unwrap();
+    let second = ParticipantIndex::from_u64(2).unwrap();
+    assert_eq!(F381::from(1u32), first.as_field());
+    let share = EVSS381::get_share_for_index(first, &params, &poly, rng)?;
+    assert!(EVSS381::check_for_index(&public_params, &poly.get_commit(), first, &share, rng)?);
+    assert!(!EVSS381::check_for_index(&public_params, &poly.get_commit(), second, &share, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_params_file() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let universal = PC381::setup(DEGREE, None, rng)?;
+    let params = EVSS381::setup_from_universal(&universal, DEGREE)?;
+    assert!(matches!(
+        EVSS381::setup_from_universal(&universal, DEGREE + 1),
+        Err(EvssError::DegreeExceeded { got, max }) if got == DEGREE + 1 && max == DEGREE
+    ));
+    let path = std::env::temp_dir().join("evss-test-params.json");
+    params.save(&path).unwrap();
+    let loaded = EVSSParams381::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let poly = EVSS381::commit(&loaded, secret, rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    assert!(EVSS381::check(&params.get_public_params(), &poly.get_commit(), &share, rng)?);
+    let small = EVSS381::setup_from_universal(&universal, DEGREE / 2)?;
+    assert_eq!(DEGREE / 2, small.degree);
+    Ok(())
+}
+
+#[test]
+fn test_public_params_from_params_bytes() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let bytes = serde_json::to_vec(&params).unwrap();
+    assert!(serde_json::from_slice::<EVSSPublicParams381>(&bytes).is_err());
+    let public_params = EVSSPublicParams381::from_params_bytes(&bytes).unwrap();
+    let poly = EVSS381::commit(&params, secret, rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    assert!(EVSS381::check(&public_params, &poly.get_commit(), &share, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_params_validate() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    params.validate()?;
+    params.get_public_params().validate()?;
+    let mut json: serde_json::Value = serde_json::to_value(&params).unwrap();
+    json["degree"] = serde_json::json!(DEGREE + 1);
+    assert!(serde_json::from_value::<EVSSParams381>(json.clone()).is_err());
+    json["degree"] = serde_json::json!(0);
+    assert!(serde_json::from_value::<EVSSParams381>(json).is_err());
+    let mut json: serde_json::Value = serde_json::to_value(params.get_public_params()).unwrap();
+    json["degree"] = serde_json::json!(DEGREE + 1);
+    assert!(serde_json::from_value::<EVSSPublicParams381>(json).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_public_params_from() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    let public_params = EVSSPublicParams381::from(&params);
+    assert_eq!(public_params.serialized_size(), params.get_public_params().serialized_size());
+    assert!(EVSS381::check(&public_params, &poly.get_commit(), &share, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_multipoint() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let public_params = params.get_public_params();
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let points: Vec<F381> = (1..4u32).map(F381::from).collect();
+    let share = EVSS381::get_share_multipoint(&points, &params, &poly, rng)?;
+    assert!(EVSS381::check_multipoint(&public_params, &poly.get_commit(), &share, rng)?);
+    let bytes = bincode::serialize(&share).unwrap();
+    let share: MultiShare381 = bincode::deserialize(&bytes).unwrap();
+    assert!(EVSS381::check_multipoint(&public_params, &poly.get_commit(), &share, rng)?);
+    let mut forged = share.clone();
+    forged.values[1] += F381::from(1u32);
+    assert!(!EVSS381::check_multipoint(&public_params, &poly.get_commit(), &forged, rng)?);
+    forged.values.pop();
+    assert!(EVSS381::check_multipoint(&public_params, &poly.get_commit(), &forged, rng).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_proof_elements() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let point = F381::from(3u32);
+    let share = EVSS381::get_share(point, &params, &poly, rng)?;
+    let (w, value) = share.proof_elements();
+    assert_eq!(value, share.value);
+    let vk = &params.verifier_key.vk;
+    let lhs = Bls12_381::pairing(
+        poly.get_commit().commit.comm.0.into_projective() - vk.g.mul(value.into_repr()),
+        vk.h,
+    );
+    let rhs = Bls12_381::pairing(w, vk.beta_h.into_projective() - vk.h.mul(point.into_repr()));
+    assert_eq!(lhs, rhs);
+    Ok(())
+}
+
+#[test]
+fn test_check_prepared_commit() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let public_params = params.get_public_params();
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let commit = poly.get_commit();
+    let prepared = commit.prepare();
+    let points: Vec<F381> = (1..6u32).map(F381::from).collect();
+    for mut sh in EVSS381::get_shares(&points, &params, &poly, rng)? {
+        assert!(EVSS381::check_prepared_commit(&public_params, &prepared, &sh, rng)?);
+        sh.value += F381::from(1u32);
+        assert!(!EVSS381::check_prepared_commit(&public_params, &prepared, &sh, rng)?);
+        assert!(!EVSS381::check(&public_params, &commit, &sh, rng)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_evaluation_domain() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let domain = params.evaluation_domain().unwrap();
+    assert_eq!(domain.size(), DEGREE.next_power_of_two());
+    let poly = EVSS381::commit(&params, secret, rng)?;
+    let points: Vec<F381> = domain.elements().collect();
+    let shares = EVSS381::get_shares_fft(&points, &params, &poly, rng)?;
+    assert_eq!(secret, EVSS381::reconstruct(&shares));
+    Ok(())
+}
+
+#[test]
+fn test_challenge() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let public_params = params.get_public_params();
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let challenge = F381::rand(rng);
+    let share = EVSS381::get_share_with_challenge(F381::from(1u32), &params, &poly, challenge, rng)?;
+    assert_eq!(challenge, share.challenge);
+    assert!(EVSS381::check_with_challenge(&public_params, &poly.get_commit(), &share, challenge, rng)?);
+    let other = challenge + F381::from(1u32);
+    assert!(!EVSS381::check_with_challenge(&public_params, &poly.get_commit(), &share, other, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_split_join() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let (public_params, blob) = params.clone().split();
+    let public_params: EVSSPublicParams381 = serde_json::from_str(&serde_json::to_string(&public_params).unwrap()).unwrap();
+    let blob: CommitterKeyBlob381 = bincode::deserialize(&bincode::serialize(&blob).unwrap()).unwrap();
+    let joined = EVSSParams381::join(public_params.clone(), blob.clone())?;
+    assert_eq!(params.serialized_size(), joined.serialized_size());
+    let poly = EVSS381::commit(&joined, secret, rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &joined, &poly, rng)?;
+    assert!(EVSS381::check(&public_params, &poly.get_commit(), &share, rng)?);
+    let (_, small_blob) = EVSS381::setup(2, rng)?.split();
+    assert!(matches!(
+        EVSSParams381::join(public_params, small_blob),
+        Err(EvssError::DegreeExceeded { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_share_id() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let other = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    let copy: EVSSShare381 = bincode::deserialize(&bincode::serialize(&share).unwrap()).unwrap();
+    assert_eq!(share.id(&poly.get_commit()), copy.id(&poly.get_commit()));
+    assert_ne!(share.id(&poly.get_commit()), share.id(&other.get_commit()));
+    let reopened = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    assert_ne!(share.id(&poly.get_commit()), reopened.id(&poly.get_commit()));
+    Ok(())
+}
+
+#[test]
+fn test_self_check() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let mut poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    assert!(poly.self_check(&params, F381::from(1u32), rng));
+    poly.polynomial.coeffs[1] += F381::from(1u32);
+    assert!(!poly.self_check(&params, F381::from(1u32), rng));
+    Ok(())
+}
+
+#[test]
+fn test_share_quotient() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let point = F381::from(5u32);
+    let quotient = EVSS381::share_quotient(point, &poly);
+    let linear = Poly381::from_coefficients_vec(vec![-point, F381::from(1u32)]);
+    let constant = Poly381::from_coefficients_vec(vec![poly.evaluate(point)]);
+    assert_eq!(poly.polynomial, &(&quotient * &linear) + &constant);
+    let share = EVSS381::get_share(point, &params, &poly, rng)?;
+    let quotient_commit = EVSS381::commit_polynomial(&params, &quotient, rng)?.get_commit();
+    assert_eq!(share.proof.w, quotient_commit.commit.comm.0);
+    Ok(())
+}
+
+#[test]
+fn test_hiding_bound() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    assert!(matches!(
+        EVSS381::commit_with_hiding_bound(&EVSS381::setup(DEGREE, rng)?, secret, Some(1), rng),
+        Err(EvssError::HidingBoundExceeded { got: 1, max: 0 })
+    ));
+    let params = EVSS381::setup_with_hiding_bound(DEGREE, 1, rng)?;
+    let public_params = params.get_public_params();
+    let poly = EVSS381::commit(&params, secret, rng)?;
+    let plain = EVSS381::commit_polynomial(&params, &poly.polynomial, rng)?;
+    assert_eq!(poly.get_commit(), plain.get_commit());
+    let first = EVSS381::commit_polynomial_with_hiding_bound(&params, &poly.polynomial, Some(1), rng)?;
+    let second = EVSS381::commit_polynomial_with_hiding_bound(&params, &poly.polynomial, Some(1), rng)?;
+    assert_ne!(first.get_commit(), second.get_commit());
+    let points: Vec<F381> = (1..DEGREE as u32 + 1).map(F381::from).collect();
+    for hiding in [first, second, EVSS381::commit_with_hiding_bound(&params, secret, Some(1), rng)?] {
+        let shares = EVSS381::get_shares(&points, &params, &hiding, rng)?;
+        for sh in &shares {
+            assert!(EVSS381::check(&public_params, &hiding.get_commit(), sh, rng)?);
+        }
+        assert_eq!(secret, EVSS381::reconstruct(&shares));
+    }
+    assert!(matches!(
+        EVSS381::commit_with_hiding_bound(&params, secret, Some(2), rng),
+        Err(EvssError::HidingBoundExceeded { got: 2, max: 1 })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_params_new() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let pp = PC381::setup(DEGREE, None, rng)?;
+    let (ck, vk) = PC381::trim(&pp, DEGREE, 0, None)?;
+    let params = EVSSParams381::new(DEGREE, ck.clone(), vk.clone())?;
+    let public_params = EVSSPublicParams381::new(DEGREE, vk.clone())?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    assert!(EVSS381::check(&public_params, &poly.get_commit(), &share, rng)?);
+    assert!(matches!(
+        EVSSParams381::new(DEGREE + 1, ck, vk.clone()),
+        Err(EvssError::DegreeExceeded { .. })
+    ));
+    assert!(matches!(EVSSPublicParams381::new(0, vk), Err(EvssError::DegreeIsZero)));
+    Ok(())
+}
+
+#[test]
+fn test_u64_wrappers() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit_u64(&params, 42, rng)?;
+    let mut shares = Vec::new();
+    for index in 1..=DEGREE as u64 + 1 {
+        let share = EVSS381::get_share_u64(index, &params, &poly, rng)?;
+        assert_eq!(F381::from(index), share.point);
+        shares.push(share);
+    }
+    assert_eq!(F381::from(42u64), EVSS381::reconstruct(&shares));
+    assert!(matches!(
+        EVSS381::get_share_u64(0, &params, &poly, rng),
+        Err(EvssError::ZeroPoint)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_update_coefficient() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let public_params = params.get_public_params();
+    let mut poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let masked = F381::rand(rng);
+    poly.update_coefficient(3, masked, &params)?;
+    poly.update_coefficient(DEGREE, F381::rand(rng), &params)?;
+    assert_eq!(masked, poly.polynomial.coeffs[3]);
+    let recommitted = EVSS381::commit_polynomial(&params, &poly.polynomial, rng)?;
+    assert_eq!(recommitted.get_commit(), poly.get_commit());
+    let points: Vec<F381> = (1..DEGREE as u32 + 2).map(F381::from).collect();
+    for sh in EVSS381::get_shares(&points, &params, &poly, rng)? {
+        assert!(EVSS381::check(&public_params, &poly.get_commit(), &sh, rng)?);
+    }
+    assert!(matches!(
+        poly.update_coefficient(DEGREE + 1, masked, &params),
+        Err(EvssError::DegreeExceeded { .. })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_reconstruct_commitment() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = F381::rand(rng);
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let outer = EVSS381::commit(&params, secret, rng)?;
+    let points: Vec<F381> = (1..DEGREE as u32 + 1).map(F381::from).collect();
+    let mut commit_shares = Vec::new();
+    let mut combined = Poly381::zero();
+    for (&point, coeff) in points.iter().zip(EVSS381::lagrange_coefficients(&points)) {
+        let inner = EVSS381::commit(&params, outer.evaluate(point), rng)?;
+        combined += (coeff, &inner.polynomial);
+        commit_shares.push((point, inner.get_commit()));
+    }
+    assert_eq!(secret, combined.coeffs[0]);
+    let expected = EVSS381::commit_polynomial(&params, &combined, rng)?.get_commit();
+    assert_eq!(expected, EVSS381::reconstruct_commitment(&commit_shares)?);
+    assert!(matches!(
+        EVSS381::reconstruct_commitment(&[]),
+        Err(EvssError::InsufficientShares { have: 0, need: 1 })
+    ));
+    commit_shares.push(commit_shares[0].clone());
+    assert!(matches!(
+        EVSS381::reconstruct_commitment(&commit_shares),
+        Err(EvssError::DuplicatePoint)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_deserialize_malformed() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    let bytes = bincode::serialize(&share).unwrap();
+    for len in 0..bytes.len() {
+        assert!(bincode::deserialize::<EVSSShare381>(&bytes[..len]).is_err());
+    }
+    let mut flipped = bytes.clone();
+    let last = flipped.len() - 1;
+    flipped[last] ^= 0xff;
+    assert!(bincode::deserialize::<EVSSShare381>(&flipped).is_err());
+    assert!(serde_json::from_str::<EVSSCommit381>(r#"{"commit":"zz"}"#).is_err());
+    assert!(serde_json::from_str::<EVSSCommit381>(r#"{"commit":[1,2,3]}"#).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_max_seq_len() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let mut bytes = Vec::new();
+    poly.commit.serialize(&mut bytes).unwrap();
+    let json = format!(r#"{{"commit":{}}}"#, serde_json::to_string(&bytes).unwrap());
+    assert!(serde_json::from_str::<EVSSCommit381>(&json).is_ok());
+    let result = crate::ark_serde::with_max_seq_len(bytes.len() - 1, || {
+        serde_json::from_str::<EVSSCommit381>(&json)
+    });
+    assert!(result.is_err());
+    assert_eq!(crate::ark_serde::max_seq_len(), crate::ark_serde::DEFAULT_MAX_SEQ_LEN);
+    assert!(serde_json::from_str::<EVSSCommit381>(&json).is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_check_detailed() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, poly, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let commit = poly.get_commit();
+    let point = F381::from(1u32);
+    let mut share = EVSS381::get_share(point, &params, &poly, rng)?;
+    let value = poly.evaluate(point);
+    assert_eq!(CheckOutcome::Valid, EVSS381::check_detailed(&public_params, &commit, &share, rng)?);
+    assert_eq!(CheckOutcome::Valid, EVSS381::check_value(&public_params, &commit, &share, value, rng)?);
+    assert_eq!(
+        CheckOutcome::ValueMismatch,
+        EVSS381::check_value(&public_params, &commit, &share, value + F381::from(1u32), rng)?
+    );
+    share.value += F381::from(1u32);
+    assert_eq!(CheckOutcome::ProofInvalid, EVSS381::check_detailed(&public_params, &commit, &share, rng)?);
+    assert_eq!(CheckOutcome::ProofInvalid, EVSS381::check_value(&public_params, &commit, &share, value, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_commit_eq_and_digest() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let other = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let decoded: EVSSCommit381 = bincode::deserialize(&bincode::serialize(&poly.get_commit()).unwrap()).unwrap();
+    assert_eq!(poly.get_commit(), decoded);
+    assert_eq!(poly.get_commit().digest(), decoded.digest());
+    assert_ne!(poly.get_commit(), other.get_commit());
+    assert_eq!(poly.get_commit().serialized_size(), poly.get_commit().digest().len());
+    Ok(())
+}
+
+#[test]
+fn test_hash() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    let mut commits = std::collections::HashMap::new();
+    commits.insert(poly.get_commit(), 1);
+    assert_eq!(Some(&1), commits.get(&poly.get_commit()));
+    let mut shares = std::collections::HashSet::new();
+    shares.insert(share.clone());
+    assert!(!shares.insert(share.clone()));
+    let mut other = share.clone();
+    other.proof.w = Default::default();
+    assert_ne!(share, other);
+    assert!(shares.insert(other));
+    Ok(())
+}
+
+#[test]
+fn test_commit_bytes() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let secret = [7u8; 32];
+    let Fixture { params, points, .. } = fixture(rng)?;
+    let poly = EVSS381::commit_bytes(&params, &secret, rng)?;
+    let shares = EVSS381::get_shares(&points, &params, &poly, rng)?;
+    let mut expected = Vec::new();
+    EVSS381::secret_from_bytes(&secret).serialize(&mut expected).unwrap();
+    assert_eq!(expected, EVSS381::reconstruct_bytes(&shares)?);
+    assert_ne!(EVSS381::secret_from_bytes(&secret), EVSS381::secret_from_bytes(&[7u8; 31]));
+    Ok(())
+}
+
+#[test]
+fn test_commit_chunked() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, points, .. } = fixture(rng)?;
+    assert_eq!(31, EVSS381::chunk_size());
+    for len in &[0usize, 30, 31, 64] {
+        let secret: Vec<u8> = (0..*len).map(|i| if i % 2 == 0 { 0x80 } else { 0 }).collect();
+        let polys = EVSS381::commit_chunked(&params, &secret, rng)?;
+        assert_eq!(len / 31 + 1, polys.len());
+        let share_sets = polys
+            .iter()
+            .map(|poly| EVSS381::get_shares(&points, &params, poly, rng))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(secret, EVSS381::reconstruct_chunked(&share_sets)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_refresh() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, points } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let (refreshed, mask) = EVSS381::refresh(&params, &poly, rng)?;
+    assert!(mask.evaluate(F381::zero()).is_zero());
+    let expected = EVSSCommit381::linear_combination(
+        &[poly.get_commit(), mask.get_commit()],
+        &[F381::from(1u32), F381::from(1u32)],
+    )?;
+    assert_eq!(expected, refreshed.get_commit());
+    let shares = EVSS381::get_shares(&points, &params, &refreshed, rng)?;
+    for sh in &shares {
+        assert!(EVSS381::check(&public_params, &refreshed.get_commit(), sh, rng)?);
+        assert!(!EVSS381::check(&public_params, &poly.get_commit(), sh, rng)?);
+    }
+    assert_eq!(secret, EVSS381::reconstruct(&shares));
+    Ok(())
+}
+
+#[test]
+fn test_reshare() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, params, poly, points: old_points } = fixture(rng)?;
+    let new_degree = DEGREE / 2;
+    let new_params = EVSS381::setup(new_degree, rng)?;
+    let new_points: Vec<F381> = share_points(new_degree + 1)
+        .into_iter()
+        .map(|p| p + F381::from(100u32))
+        .collect();
+    let old_shares = EVSS381::get_shares(&old_points, &params, &poly, rng)?;
+    let mut subshares = vec![Vec::new(); new_points.len()];
+    for sh in &old_shares {
+        let sub = EVSS381::reshare_step(&new_params, sh, rng)?;
+        for (j, sub_share) in EVSS381::get_shares(&new_points, &new_params, &sub, rng)?.into_iter().enumerate() {
+            assert!(EVSS381::check(&new_params.get_public_params(), &sub.get_commit(), &sub_share, rng)?);
+            subshares[j].push(sub_share);
+        }
+    }
+    let new_values = subshares
+        .iter()
+        .map(|subs| EVSS381::combine_subshares(&old_points, subs))
+        .collect::<Result<Vec<_>, _>>()?;
+    let coeffs = EVSS381::lagrange_coefficients(&new_points);
+    assert_eq!(secret, EVSS381::reconstruct_with_coefficients(&new_values, &coeffs)?);
+    assert!(matches!(
+        EVSS381::combine_subshares(&old_points[1..], &subshares[0]),
+        Err(EvssError::LengthMismatch { .. })
+    ));
+    let mut mixed = subshares[0].clone();
+    mixed[1] = subshares[1][1].clone();
+    assert!(matches!(
+        EVSS381::combine_subshares(&old_points, &mixed),
+        Err(EvssError::PointMismatch)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_aggregate() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, points, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let secrets: Vec<F381> = (0..3).map(|_| F381::rand(rng)).collect();
+    let polys = EVSS381::commit_many(&params, &secrets, rng)?;
+    let commits: Vec<EVSSCommit381> = polys.iter().map(|poly| poly.get_commit()).collect();
+    let commit = EVSS381::aggregate_commitments(&commits)?;
+    let mut shares = Vec::new();
+    for &point in &points {
+        let mine = polys
+            .iter()
+            .map(|poly| EVSS381::get_share(point, &params, poly, rng))
+            .collect::<Result<Vec<_>, _>>()?;
+        let share = EVSS381::aggregate_shares(&mine)?;
+        assert!(EVSS381::check(&public_params, &commit, &share, rng)?);
+        shares.push(share);
+    }
+    assert_eq!(secrets.iter().copied().sum::<F381>(), EVSS381::reconstruct(&shares));
+    assert!(matches!(
+        EVSS381::aggregate_shares(&shares[..2]),
+        Err(EvssError::PointMismatch)
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_complaint() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let public_params = params.get_public_params();
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let commit = poly.get_commit();
+    let mut share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    assert!(EVSS381::make_complaint(&public_params, &commit, &share, rng)?.is_none());
+    let forged = Complaint381 { share: share.clone() };
+    assert!(!EVSS381::verify_complaint(&public_params, &commit, &forged, rng)?);
+    share.value += F381::from(1u32);
+    let complaint = EVSS381::make_complaint(&public_params, &commit, &share, rng)?.unwrap();
+    let complaint: Complaint381 = serde_json::from_str(&serde_json::to_string(&complaint).unwrap()).unwrap();
+    assert!(EVSS381::verify_complaint(&public_params, &commit, &complaint, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_check_multi() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let public_params = params.get_public_params();
+    let secrets: Vec<F381> = (0..4).map(|_| F381::rand(rng)).collect();
+    let polys = EVSS381::commit_many(&params, &secrets, rng)?;
+    let mut pairs = Vec::new();
+    for poly in &polys {
+        let share = EVSS381::get_share(F381::from(1u32), &params, poly, rng)?;
+        pairs.push((poly.get_commit(), share));
+    }
+    assert!(EVSS381::check_multi(&public_params, &pairs, rng)?);
+    pairs[2].1.value += F381::from(1u32);
+    assert!(!EVSS381::check_multi(&public_params, &pairs, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_commit_batch() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let public_params = params.get_public_params();
+    let polys: Vec<Poly381> = (0..4).map(|_| Poly381::rand(DEGREE - 1, rng)).collect();
+    let committed = EVSS381::commit_batch(&params, &polys, rng)?;
+    assert_eq!(polys.len(), committed.len());
+    for (poly, evss_poly) in polys.iter().zip(&committed) {
+        assert_eq!(*poly, evss_poly.polynomial);
+        let share = EVSS381::get_share(F381::from(1u32), &params, evss_poly, rng)?;
+        assert!(EVSS381::check(&public_params, &evss_poly.get_commit(), &share, rng)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_debug_fingerprint() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let share = EVSS381::get_share(F381::from(1u32), &params, &poly, rng)?;
+    let digest = hex::encode(poly.get_commit().digest());
+    let debug = format!("{:?}", poly.get_commit());
+    assert!(debug.contains(&digest[..8]) && debug.contains(&digest[digest.len() - 8..]));
+    let mut proof = Vec::new();
+    share.proof.serialize(&mut proof).unwrap();
+    assert!(format!("{:?}", share).contains(&hex::encode(&proof[..4])));
+    Ok(())
+}
+
+#[test]
+fn test_dealing() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { params, poly, points, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let dealing = Dealing381 {
+        commit: poly.get_commit(),
+        shares: EVSS381::get_shares(&points, &params, &poly, rng)?,
+    };
+    let mut decoded: Dealing381 = serde_json::from_str(&serde_json::to_string(&dealing).unwrap()).unwrap();
+    assert!(decoded.verify_all(&public_params, rng)?);
+    decoded.shares[3].value += F381::from(1u32);
+    assert!(!decoded.verify_all(&public_params, rng)?);
+    Ok(())
+}
+
+#[test]
+fn test_secret_eq_ct() {
+    let rng = &mut test_rng();
+    let a = F381::rand(rng);
+    let b = F381::rand(rng);
+    assert!(bool::from(EVSS381::secret_eq_ct(&a, &a)));
+    assert!(!bool::from(EVSS381::secret_eq_ct(&a, &b)));
+}
+
+#[test]
+fn test_transcript() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let public_params = params.get_public_params();
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let point = F381::from(1u32);
+    let transcript = Blake2bTranscript::with_domain(b"test");
+    let share = EVSS381::get_share_with_transcript(point, &params, &poly, transcript.clone(), rng)?;
+    assert!(EVSS381::check_with_transcript(&public_params, &poly.get_commit(), &share, transcript.clone(), rng)?);
+    let mut expected = transcript;
+    EvssTranscript::<F381>::append_commitment(&mut expected, &poly.commit);
+    expected.append_point(&point);
+    expected.append_value(&share.value);
+    assert_eq!(EvssTranscript::<F381>::challenge_scalar(&mut expected), share.challenge);
+    assert!(!share.challenge.is_zero());
+    let default = EVSS381::get_share_fs(point, &params, &poly, rng)?;
+    assert_ne!(default.challenge, share.challenge);
+    Ok(())
+}
+
+#[cfg(feature = "merlin")]
+#[test]
+fn test_merlin_transcript() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let params = EVSS381::setup(DEGREE, rng)?;
+    let poly = EVSS381::commit(&params, F381::rand(rng), rng)?;
+    let transcript = merlin::Transcript::new(b"rust-evss test");
+    let share = EVSS381::get_share_with_transcript(F381::from(1u32), &params, &poly, transcript.clone(), rng)?;
+    assert!(EVSS381::check_with_transcript(&params.get_public_params(), &poly.get_commit(), &share, transcript, rng)?);
+    Ok(())
+}
+
+#[cfg(feature = "poseidon")]
+#[test]
+fn test_poseidon_transcript() -> Result<(), EvssError381> {
+    use crate::transcript::{EvssTranscript, PoseidonTranscript};
+    let rng = &mut test_rng();
+    let Fixture { params, poly, .. } = fixture(rng)?;
+    let public_params = params.get_public_params();
+    let share = EVSS381::get_share_with_transcript(F381::from(1u32), &params, &poly, PoseidonTranscript::new(), rng)?;
+    assert!(EVSS381::check_with_transcript(&public_params, &poly.get_commit(), &share, PoseidonTranscript::new(), rng)?);
+    let squeeze = |mut t: PoseidonTranscript<F381>, value: F381| {
+        t.append_commitment(&poly.get_commit().commit);
+        t.append_point(&share.point);
+        t.append_value(&value);
+        t.challenge_scalar()
+    };
+    assert_eq!(share.challenge, squeeze(PoseidonTranscript::new(), share.value));
+    assert_ne!(share.challenge, squeeze(PoseidonTranscript::new(), share.value + F381::from(1u32)));
+    assert_ne!(share.challenge, squeeze(PoseidonTranscript::with_domain(b"another domain"), share.value));
+    Ok(())
+}
+
+#[test]
+fn test_reconstruct_from_evaluations() -> Result<(), EvssError381> {
+    let rng = &mut test_rng();
+    let Fixture { secret, poly, mut points, .. } = fixture(rng)?;
+    let values: Vec<F381> = points.iter().map(|&p| poly.evaluate(p)).collect();
+    assert_eq!(secret, EVSS381::reconstruct_from_evaluations(&points, &values)?);
+    assert!(matches!(
+        EVSS381::reconstruct_from_evaluations(&points[1..], &values),
+        Err(EvssError::LengthMismatch { .. })
+    ));
+    points[0] = points[1];
+    assert!(matches!(
+        EVSS381::reconstruct_from_evaluations(&points, &values),
+        Err(EvssError::DuplicatePoint)
+    ));
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn prop_reconstruct(
+        degree in 1usize..8,
+        indices in prop::collection::btree_set(1u64..1 << 32, 9),
+        seed in any::<[u8; 32]>(),
+    ) {
+        let rng = &mut ChaCha20Rng::from_seed(seed);
+        let secret = F381::rand(rng);
+        let params = EVSS381::setup(degree, rng).unwrap();
+        let public_params = params.get_public_params();
+        let poly = EVSS381::commit(&params, secret, rng).unwrap();
+        let points: Vec<F381> = indices.iter().take(degree + 1).map(|&i| F381::from(i)).collect();
+        let mut shares = EVSS381::get_shares(&points, &params, &poly, rng).unwrap();
+        prop_assert_eq!(secret, EVSS381::reconstruct(&shares));
+        prop_assert_eq!(secret, EVSS381::try_reconstruct(&public_params, &shares).unwrap());
+        let is_insufficient = matches!(
+            EVSS381::try_reconstruct(&public_params, &shares[1..]),
+            Err(EvssError::InsufficientShares { have, need }) if have == degree && need == degree + 1
+        );
+        prop_assert!(is_insufficient);
+        shares[0] = shares[degree].clone();
+        let is_duplicate = matches!(
+            EVSS381::try_reconstruct(&public_params, &shares),
+            Err(EvssError::DuplicatePoint)
+        );
+        prop_assert!(is_duplicate);
+    }
+}
+
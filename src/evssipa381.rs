@@ -0,0 +1,77 @@
+//! eVSS over BLS12-381 using the inner-product-argument commitment scheme, which needs no
+//! trusted setup. The price is proof size: an opening carries up to `2 * log2(degree + 1) + 2` group
+//! elements plus two scalars, where a `MarlinKZG10` opening is one or two group elements.
+//! Verification is also linear in the degree rather than a constant number of pairings.
+
+pub use ark_bls12_381::{Fr, G1Affine};
+pub use ark_poly::univariate::DensePolynomial;
+pub use ark_poly_commit::ipa_pc::InnerProductArgPC;
+pub use blake2::Blake2b;
+
+pub type FIpa381 = Fr;
+pub type PolyIpa381 = DensePolynomial<FIpa381>;
+pub type PCIpa381 = InnerProductArgPC<G1Affine, Blake2b, PolyIpa381>;
+pub type EVSSIpa381 = crate::evss::EVSS<FIpa381, PolyIpa381, PCIpa381>;
+pub type EVSSParamsIpa381 = crate::evss::EVSSParams<FIpa381, PolyIpa381, PCIpa381>;
+pub type EVSSPublicParamsIpa381 = crate::evss::EVSSPublicParams<FIpa381, PolyIpa381, PCIpa381>;
+pub type EVSSPolynomialIpa381 = crate::evss::EVSSPolynomial<FIpa381, PolyIpa381, PCIpa381>;
+pub type EVSSCommitIpa381 = crate::evss::EVSSCommit<FIpa381, PolyIpa381, PCIpa381>;
+pub type EVSSShareIpa381 = crate::evss::EVSSShare<FIpa381, PolyIpa381, PCIpa381>;
+pub type EVSSProofIpa381 = <PCIpa381 as PolynomialCommitment<FIpa381, PolyIpa381>>::Proof;
+pub type EvssErrorIpa381 =
+    crate::error::EvssError<<PCIpa381 as PolynomialCommitment<FIpa381, PolyIpa381>>::Error>;
+
+pub use ark_ff::{Field, PrimeField, UniformRand};
+pub use ark_poly_commit::PolynomialCommitment;
+pub use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+#[cfg(test)]
+mod tests {
+
+    use crate::evssipa381::*;
+
+    use ark_std::test_rng;
+
+    use serde_json;
+
+    const DEGREE: usize = 7;
+    const INDEX_BEGIN: usize = 1;
+
+    #[test]
+    fn test_functionality() -> Result<(), EvssErrorIpa381> {
+        let rng = &mut test_rng();
+        let secret = FIpa381::rand(rng);
+        let params = EVSSIpa381::setup(DEGREE, rng)?;
+        let poly = EVSSIpa381::commit(&params, secret, rng)?;
+        let mut shares = Vec::new();
+        for i in INDEX_BEGIN..INDEX_BEGIN + DEGREE + 1 {
+            shares.push(EVSSIpa381::get_share(FIpa381::from(i as u32), &params, &poly, rng)?);
+        }
+        for sh in &shares {
+            assert!(EVSSIpa381::check(&params.get_public_params(), &poly.get_commit(), sh, rng)?);
+        }
+        let mut forged = shares[0].clone();
+        forged.value += FIpa381::from(1u32);
+        assert!(!EVSSIpa381::check(&params.get_public_params(), &poly.get_commit(), &forged, rng)?);
+        assert_eq!(secret, EVSSIpa381::reconstruct(&shares));
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde() -> Result<(), serde_json::Error> {
+        let rng = &mut test_rng();
+        let secret = FIpa381::rand(rng);
+        let params = EVSSIpa381::setup(DEGREE, rng).expect("");
+        let poly = EVSSIpa381::commit(&params, secret, rng).expect("");
+        let share = EVSSIpa381::get_share(FIpa381::from(INDEX_BEGIN as u32), &params, &poly, rng)
+            .expect("");
+        let public_params: EVSSPublicParamsIpa381 =
+            serde_json::from_str(&serde_json::to_string(&params.get_public_params())?)?;
+        let commit: EVSSCommitIpa381 =
+            serde_json::from_str(&serde_json::to_string(&poly.get_commit())?)?;
+        let share: EVSSShareIpa381 = serde_json::from_str(&serde_json::to_string(&share)?)?;
+        assert!(EVSSIpa381::check(&public_params, &commit, &share, rng).expect(""));
+        Ok(())
+    }
+
+}
@@ -0,0 +1,171 @@
+//! Feldman VSS: every coefficient is committed to as `coeff * G`, so shares are verified with
+//! public group operations and no trusted setup, at the cost of a commitment that grows with
+//! the degree.
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, UniformRand};
+use ark_poly::univariate::DensePolynomial;
+use ark_poly::{Polynomial, UVPolynomial};
+use ark_std::{iter::once, marker::PhantomData, vec::Vec};
+
+use crate::ark_serde::{canonical_deserialize, canonical_serialize};
+use crate::error::EvssError;
+use crate::helper::{lagrange_coefficients, validate_points};
+
+use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct FeldmanPolynomial<G: ProjectiveCurve> {
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub polynomial: DensePolynomial<G::ScalarField>,
+    pub commit: FeldmanCommit<G>,
+}
+
+impl<G: ProjectiveCurve> FeldmanPolynomial<G> {
+
+    pub fn get_commit(&self) -> FeldmanCommit<G> {
+        self.commit.clone()
+    }
+
+}
+
+impl<G: ProjectiveCurve> std::fmt::Debug for FeldmanPolynomial<G> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FeldmanPolynomial")
+         .field("polynomial", &self.polynomial)
+         .finish()
+    }
+
+}
+
+impl<G: ProjectiveCurve> Clone for FeldmanPolynomial<G> {
+
+    fn clone(&self) -> Self {
+        FeldmanPolynomial {
+            polynomial: self.polynomial.clone(),
+            commit: self.commit.clone(),
+        }
+    }
+
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FeldmanCommit<G: ProjectiveCurve> {
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub commits: Vec<G::Affine>,
+}
+
+impl<G: ProjectiveCurve> std::fmt::Debug for FeldmanCommit<G> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FeldmanCommit")
+         .field("commits", &self.commits)
+         .finish()
+    }
+
+}
+
+impl<G: ProjectiveCurve> Clone for FeldmanCommit<G> {
+
+    fn clone(&self) -> Self {
+        FeldmanCommit {
+            commits: self.commits.clone(),
+        }
+    }
+
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FeldmanShare<G: ProjectiveCurve> {
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub point: G::ScalarField,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub value: G::ScalarField,
+}
+
+impl<G: ProjectiveCurve> std::fmt::Debug for FeldmanShare<G> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FeldmanShare")
+         .field("point", &self.point)
+         .field("value", &self.value)
+         .finish()
+    }
+
+}
+
+impl<G: ProjectiveCurve> Clone for FeldmanShare<G> {
+
+    fn clone(&self) -> Self {
+        FeldmanShare {
+            point: self.point,
+            value: self.value,
+        }
+    }
+
+}
+
+pub struct Feldman<G: ProjectiveCurve> {
+    _g: PhantomData<G>,
+}
+
+impl<G: ProjectiveCurve> Feldman<G> {
+
+    /// `degree` means the same as `EVSSParams::degree`: the polynomial gets `degree`
+    /// coefficients, the secret and `degree - 1` random ones.
+    pub fn commit<R: RngCore>(
+        degree: usize,
+        secret: G::ScalarField,
+        rng: &mut R,
+    ) -> FeldmanPolynomial<G> {
+        let coeffs: Vec<G::ScalarField> = once(secret)
+            .chain((1..degree).map(|_| G::ScalarField::rand(rng)))
+            .collect();
+        let generator = G::prime_subgroup_generator();
+        let commits = coeffs
+            .iter()
+            .map(|c| generator.mul(c.into_repr()))
+            .collect::<Vec<G>>();
+        FeldmanPolynomial {
+            polynomial: DensePolynomial::from_coefficients_vec(coeffs),
+            commit: FeldmanCommit {
+                commits: G::batch_normalization_into_affine(&commits),
+            },
+        }
+    }
+
+    pub fn get_share(point: G::ScalarField, poly: &FeldmanPolynomial<G>) -> FeldmanShare<G> {
+        FeldmanShare {
+            point,
+            value: poly.polynomial.evaluate(&point),
+        }
+    }
+
+    /// Checks `value * G == sum_i point^i * commits[i]`.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check(commit: &FeldmanCommit<G>, share: &FeldmanShare<G>) -> bool {
+        let expected = commit.commits.iter().rev().fold(G::zero(), |acc, c| {
+            acc.mul(share.point.into_repr()) + c.into_projective()
+        });
+        G::prime_subgroup_generator().mul(share.value.into_repr()) == expected
+    }
+
+    /// Interpolates the secret from shares of a polynomial committed with `degree`. Errors with
+    /// `InsufficientShares` on fewer than `degree` shares and `DuplicatePoint` on a repeated point.
+    pub fn reconstruct<E>(degree: usize, shares: &[FeldmanShare<G>]) -> Result<G::ScalarField, EvssError<E>> {
+        let points: Vec<G::ScalarField> = shares.iter().map(|sh| sh.point).collect();
+        validate_points(&points, degree.max(1))?;
+        Ok(shares
+            .iter()
+            .zip(lagrange_coefficients(&points))
+            .map(|(sh, c)| sh.value * c)
+            .sum())
+    }
+
+}
@@ -1,11 +1,384 @@
-use ark_ff::Field;
+//! Polynomial and hashing utilities shared by the sharing schemes. Only `vanishing_polynomial`
+//! is part of the public API; the rest are crate-internal.
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{FftField, Field, PrimeField, Zero};
+use ark_poly::univariate::DensePolynomial;
 use ark_poly::UVPolynomial;
 use ark_poly_commit::{LabeledCommitment, LabeledPolynomial, PolynomialCommitment};
+use ark_std::{cfg_iter, collections::BTreeSet, vec::Vec};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use blake2::{Blake2b, Blake2s, Digest};
+
+use crate::error::EvssError;
+
+/// Label for a polynomial committed or opened on its own. Polynomials handled together in one
+/// `PC` call need distinct labels.
+pub(crate) const DEFAULT_LABEL: &str = "";
+
+pub(crate) fn label_polynomial<F: Field, P: UVPolynomial<F>>(label: &str, polynomial: &P) -> LabeledPolynomial<F, P> {
+    label_polynomial_bounded(label, polynomial, None)
+}
+
+pub(crate) fn label_polynomial_bounded<F: Field, P: UVPolynomial<F>>(label: &str, polynomial: &P, degree_bound: Option<usize>) -> LabeledPolynomial<F, P> {
+    LabeledPolynomial::new(label.to_owned(), polynomial.clone(), degree_bound, None)
+}
+
+pub(crate) fn label_polynomial_hiding<F: Field, P: UVPolynomial<F>>(label: &str, polynomial: &P, hiding_bound: Option<usize>) -> LabeledPolynomial<F, P> {
+    LabeledPolynomial::new(label.to_owned(), polynomial.clone(), None, hiding_bound)
+}
+
+pub(crate) fn label_commit<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>>(label: &str, commit: &PC::Commitment) -> LabeledCommitment<PC::Commitment> {
+    label_commit_bounded::<F, P, PC>(label, commit, None)
+}
+
+pub(crate) fn label_commit_bounded<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>>(label: &str, commit: &PC::Commitment, degree_bound: Option<usize>) -> LabeledCommitment<PC::Commitment> {
+    LabeledCommitment::new(label.to_owned(), commit.clone(), degree_bound)
+}
+
+/// Short hex form of `bytes` for `Debug` output: the first and last four bytes.
+pub(crate) fn fingerprint(bytes: &[u8]) -> String {
+    if bytes.len() <= 8 {
+        hex::encode(bytes)
+    } else {
+        format!("{}..{}", hex::encode(&bytes[..4]), hex::encode(&bytes[bytes.len() - 4..]))
+    }
+}
+
+pub(crate) fn fs_hash<F: Field>(domain: &[u8], buf: &[u8]) -> F {
+    let mut counter: u64 = 0;
+    loop {
+        let digest = Blake2b::new()
+            .chain(domain)
+            .chain(buf)
+            .chain(counter.to_le_bytes())
+            .finalize();
+        if let Some(ch) = F::from_random_bytes(&digest) {
+            return ch;
+        }
+        counter += 1;
+    }
+}
+
+pub(crate) fn hash_to_field<F: PrimeField>(domain: &[u8], buf: &[u8]) -> F {
+    let digest = Blake2b::new().chain(domain).chain(buf).finalize();
+    F::from_le_bytes_mod_order(&digest)
+}
+
+pub(crate) fn hash_to_bytes(domain: &[u8], buf: &[u8]) -> [u8; 32] {
+    Blake2s::new().chain(domain).chain(buf).finalize().into()
+}
+
+/// Errors with `InsufficientShares` if there are fewer than `need` points, then with
+/// `DuplicatePoint` if any point repeats.
+pub(crate) fn validate_points<F: Field, E>(points: &[F], need: usize) -> Result<(), EvssError<E>> {
+    if points.len() < need {
+        return Err(EvssError::InsufficientShares {
+            have: points.len(),
+            need,
+        });
+    }
+    let mut seen = BTreeSet::new();
+    if points.iter().all(|p| seen.insert(p)) {
+        Ok(())
+    } else {
+        Err(EvssError::DuplicatePoint)
+    }
+}
+
+pub(crate) fn lagrange_coefficients<F: Field>(points: &[F]) -> Vec<F> {
+    lagrange_coefficients_at(points, F::zero())
+}
+
+pub(crate) fn lagrange_coefficients_at<F: Field>(points: &[F], x0: F) -> Vec<F> {
+    cfg_iter!(points)
+        .map(|&x1| {
+            let mut num = F::one();
+            let mut den = F::one();
+            for &x2 in points {
+                if x1 != x2 {
+                    num *= x0 - x2;
+                    den *= x1 - x2;
+                }
+            }
+            num / den
+        })
+        .collect()
+}
+
+pub(crate) fn mul_by_linear<F: Field>(coeffs: &[F], root: F) -> Vec<F> {
+    let mut res = vec![F::zero(); coeffs.len() + 1];
+    for (i, &c) in coeffs.iter().enumerate() {
+        res[i + 1] += c;
+        res[i] -= root * c;
+    }
+    res
+}
+
+pub(crate) fn divide_by_linear<F: Field>(coeffs: &[F], root: F) -> (Vec<F>, F) {
+    let mut quotient = vec![F::zero(); coeffs.len().saturating_sub(1)];
+    let mut acc = F::zero();
+    for (i, &c) in coeffs.iter().enumerate().rev() {
+        acc = c + root * acc;
+        if i > 0 {
+            quotient[i - 1] = acc;
+        }
+    }
+    (quotient, acc)
+}
+
+/// Multi-scalar multiplication against fixed bases, for computing many MSMs over the same
+/// committer key. The table holds `2^(c * j) * g_m` for every `c`-bit window `j`, so each MSM
+/// is a single bucket pass with no doublings: `bases * windows` mixed additions plus `2^(c + 1)`
+/// to sum the buckets, against `windows * (bases + 2^(c + 1))` for Pippenger. Building the
+/// table costs about as much as a handful of plain MSMs.
+pub(crate) struct FixedBaseMsm<G: AffineCurve> {
+    window: usize,
+    tables: Vec<Vec<G>>,
+}
+
+impl<G: AffineCurve> FixedBaseMsm<G> {
+
+    pub(crate) fn new(bases: &[G]) -> Self {
+        let bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let windows = |c: usize| bits.div_ceil(c);
+        let window = (1..=16)
+            .min_by_key(|&c| bases.len() * windows(c) + (2 << c))
+            .expect("window range is non-empty");
+        let mut row: Vec<G::Projective> = bases.iter().map(|b| b.into_projective()).collect();
+        let mut tables = Vec::with_capacity(windows(window));
+        for _ in 0..windows(window) {
+            tables.push(G::Projective::batch_normalization_into_affine(&row));
+            for p in row.iter_mut() {
+                for _ in 0..window {
+                    p.double_in_place();
+                }
+            }
+        }
+        FixedBaseMsm { window, tables }
+    }
+
+    pub(crate) fn mul(&self, scalars: &[<G::ScalarField as PrimeField>::BigInt]) -> G::Projective {
+        let mut buckets = vec![G::Projective::zero(); (1 << self.window) - 1];
+        for (m, scalar) in scalars.iter().enumerate() {
+            let limbs = scalar.as_ref();
+            for (j, table) in self.tables.iter().enumerate() {
+                let digit = window_digit(limbs, j * self.window, self.window);
+                if digit != 0 {
+                    buckets[digit - 1].add_assign_mixed(&table[m]);
+                }
+            }
+        }
+        let mut running = G::Projective::zero();
+        let mut sum = G::Projective::zero();
+        for bucket in buckets.iter().rev() {
+            running += bucket;
+            sum += &running;
+        }
+        sum
+    }
+
+}
+
+fn window_digit(limbs: &[u64], start: usize, width: usize) -> usize {
+    let (limb, shift) = (start / 64, start % 64);
+    let mut v = limbs[limb] >> shift;
+    if shift + width > 64 && limb + 1 < limbs.len() {
+        v |= limbs[limb + 1] << (64 - shift);
+    }
+    (v & ((1 << width) - 1)) as usize
+}
+
+pub(crate) fn to_bigints<F: PrimeField>(coeffs: &[F]) -> Vec<F::BigInt> {
+    coeffs.iter().map(|c| c.into_repr()).collect()
+}
+
+pub(crate) fn interpolate_polynomial<F: Field>(points: &[F], values: &[F]) -> Vec<F> {
+    let mut vanishing = vec![F::one()];
+    for &x in points {
+        vanishing = mul_by_linear(&vanishing, x);
+    }
+    let mut coeffs = vec![F::zero(); points.len()];
+    for (&x, &y) in points.iter().zip(values) {
+        let (basis, _) = divide_by_linear(&vanishing, x);
+        let den = basis.iter().rev().fold(F::zero(), |acc, &c| acc * x + c);
+        let scale = y / den;
+        for (c, &b) in coeffs.iter_mut().zip(&basis) {
+            *c += scale * b;
+        }
+    }
+    coeffs
+}
+
+/// `prod (X - p)` over `points`, built as a balanced product tree so FFT multiplication does
+/// the heavy lifting. Repeated points appear with their multiplicity.
+pub fn vanishing_polynomial<F: FftField>(points: &[F]) -> DensePolynomial<F> {
+    match points.len() {
+        0 => DensePolynomial::from_coefficients_slice(&[F::one()]),
+        1 => DensePolynomial::from_coefficients_slice(&[-points[0], F::one()]),
+        n => {
+            let (left, right) = points.split_at(n / 2);
+            &vanishing_polynomial(left) * &vanishing_polynomial(right)
+        }
+    }
+}
+
+/// Point sets at or below this size are evaluated with Horner's rule on the remainder, where
+/// schoolbook work is cheaper than another level of the tree.
+const MULTIPOINT_LEAF: usize = 16;
+
+/// Node of the subproduct tree over a slice of points: `poly` vanishes exactly on them.
+struct SubproductTree<'a, F: FftField> {
+    points: &'a [F],
+    poly: DensePolynomial<F>,
+    children: Option<Box<(SubproductTree<'a, F>, SubproductTree<'a, F>)>>,
+}
+
+impl<'a, F: FftField> SubproductTree<'a, F> {
+
+    fn new(points: &'a [F]) -> Self {
+        if points.len() <= MULTIPOINT_LEAF {
+            return SubproductTree {
+                points,
+                poly: vanishing_polynomial(points),
+                children: None,
+            };
+        }
+        let (left, right) = points.split_at(points.len() / 2);
+        let (left, right) = (SubproductTree::new(left), SubproductTree::new(right));
+        SubproductTree {
+            points,
+            poly: &left.poly * &right.poly,
+            children: Some(Box::new((left, right))),
+        }
+    }
+
+    fn evaluate(&self, coeffs: &[F], out: &mut Vec<F>) {
+        let rem = remainder(coeffs, &self.poly.coeffs);
+        match &self.children {
+            Some(children) => {
+                children.0.evaluate(&rem, out);
+                children.1.evaluate(&rem, out);
+            }
+            None => out.extend(
+                self.points
+                    .iter()
+                    .map(|&x| rem.iter().rev().fold(F::zero(), |acc, &c| acc * x + c)),
+            ),
+        }
+    }
+
+}
+
+/// Evaluates `coeffs` at every point with a subproduct tree: reducing modulo the vanishing
+/// polynomial of each half and recursing costs `O(M(n) log n)` for `n` points instead of the
+/// `O(n * deg)` of evaluating point by point.
+pub(crate) fn multipoint_evaluate<F: FftField>(coeffs: &[F], points: &[F]) -> Vec<F> {
+    let mut out = Vec::with_capacity(points.len());
+    if !points.is_empty() {
+        SubproductTree::new(points).evaluate(coeffs, &mut out);
+    }
+    out
+}
+
+/// Inverse of `f` modulo `X^k` by Newton iteration; `f[0]` must be nonzero.
+fn inverse_mod_xk<F: FftField>(f: &[F], k: usize) -> DensePolynomial<F> {
+    let mut inv = DensePolynomial::from_coefficients_vec(vec![f[0].inverse().expect("constant term is zero")]);
+    let mut precision = 1;
+    while precision < k {
+        precision = (2 * precision).min(k);
+        let f_low = DensePolynomial::from_coefficients_slice(&f[..precision.min(f.len())]);
+        let mut correction = (&f_low * &inv).coeffs;
+        correction.truncate(precision);
+        for c in correction.iter_mut() {
+            *c = -*c;
+        }
+        correction.resize(precision.max(1), F::zero());
+        correction[0] += F::one() + F::one();
+        let mut next = (&inv * &DensePolynomial::from_coefficients_vec(correction)).coeffs;
+        next.truncate(precision);
+        inv = DensePolynomial::from_coefficients_vec(next);
+    }
+    inv
+}
+
+/// `num mod den` through the reversed quotient `rev(num) / rev(den) mod X^(m + 1)`, so every
+/// step is an FFT multiplication. `den` must be monic.
+fn remainder<F: FftField>(num: &[F], den: &[F]) -> Vec<F> {
+    let d = den.len() - 1;
+    if num.len() <= d {
+        return num.to_vec();
+    }
+    let m = num.len() - 1 - d;
+    let rev_den: Vec<F> = den.iter().rev().copied().collect();
+    let rev_num: Vec<F> = num.iter().rev().take(m + 1).copied().collect();
+    let mut rev_q = (&DensePolynomial::from_coefficients_vec(rev_num) * &inverse_mod_xk(&rev_den, m + 1)).coeffs;
+    rev_q.resize(m + 1, F::zero());
+    let q: Vec<F> = rev_q.into_iter().rev().collect();
+    let qd = &DensePolynomial::from_coefficients_vec(q) * &DensePolynomial::from_coefficients_slice(den);
+    let mut rem: Vec<F> = num[..d].to_vec();
+    for (r, c) in rem.iter_mut().zip(&qd.coeffs) {
+        *r -= c;
+    }
+    rem
+}
 
-pub fn label_polynomial<F: Field, P: UVPolynomial<F>>(polynomial: &P) -> LabeledPolynomial<F, P> {
-    LabeledPolynomial::new("".to_owned(), polynomial.clone(), None, None)
+/// Long division of `num` by `den`, whose leading coefficient must be nonzero.
+pub(crate) fn divide_polynomial<F: Field>(num: &[F], den: &[F]) -> (Vec<F>, Vec<F>) {
+    let mut rem = num.to_vec();
+    if num.len() < den.len() {
+        return (Vec::new(), rem);
+    }
+    let lead_inv = den.last().and_then(|c| c.inverse()).expect("leading coefficient is zero");
+    let mut quotient = vec![F::zero(); num.len() - den.len() + 1];
+    for i in (0..quotient.len()).rev() {
+        let c = rem[i + den.len() - 1] * lead_inv;
+        quotient[i] = c;
+        for (j, &d) in den.iter().enumerate() {
+            rem[i + j] -= c * d;
+        }
+    }
+    rem.truncate(den.len() - 1);
+    (quotient, rem)
 }
 
-pub fn label_commit<F: Field, P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>>(commit: &PC::Commitment) -> LabeledCommitment<PC::Commitment> {
-    LabeledCommitment::new("".to_owned(), commit.clone(), None)
+/// Solves the augmented system `rows` (each `cols` coefficients followed by the right-hand
+/// side) by Gaussian elimination, setting free variables to zero. Returns `None` if the system
+/// is inconsistent.
+pub(crate) fn solve_linear_system<F: Field>(mut rows: Vec<Vec<F>>, cols: usize) -> Option<Vec<F>> {
+    let mut pivots = Vec::new();
+    let mut rank = 0;
+    for col in 0..cols {
+        let pivot = match (rank..rows.len()).find(|&r| !rows[r][col].is_zero()) {
+            Some(r) => r,
+            None => continue,
+        };
+        rows.swap(rank, pivot);
+        let inv = rows[rank][col].inverse().unwrap();
+        for c in rows[rank].iter_mut() {
+            *c *= inv;
+        }
+        let pivot_row = rows[rank].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r != rank && !row[col].is_zero() {
+                let factor = row[col];
+                for (c, &p) in row.iter_mut().zip(&pivot_row) {
+                    *c -= factor * p;
+                }
+            }
+        }
+        pivots.push(col);
+        rank += 1;
+    }
+    if rows[rank..].iter().any(|row| !row[cols].is_zero()) {
+        return None;
+    }
+    let mut solution = vec![F::zero(); cols];
+    for (r, &col) in pivots.iter().enumerate() {
+        solution[col] = rows[r][cols];
+    }
+    Some(solution)
 }
@@ -0,0 +1,191 @@
+//! Shares that hide their value behind a Pedersen commitment `V = value * G + blinding * H`,
+//! with `G` and `H` the `g` and `gamma_g` of the KZG verifier key. For a hiding KZG
+//! commitment `C` the opening witness `w` at `point` satisfies
+//! `e(C - V, h) = e(w, beta_h - point * h)`, which is exactly the check for `V`, so the
+//! network only ever sees `V`. The holder receives `value` and `blinding` privately.
+
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{One, PrimeField, Zero};
+use ark_poly::univariate::DensePolynomial;
+use ark_poly_commit::marlin_pc::MarlinKZG10;
+use ark_poly_commit::PolynomialCommitment;
+use ark_std::{iter::once, marker::PhantomData, vec::Vec};
+
+use crate::ark_serde::{canonical_deserialize, canonical_serialize};
+use crate::error::EvssError;
+use crate::evss::*;
+use crate::helper::{label_commit, label_polynomial_hiding, lagrange_coefficients, validate_points, DEFAULT_LABEL};
+
+use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
+
+type Poly<E> = DensePolynomial<<E as PairingEngine>::Fr>;
+type PC<E> = MarlinKZG10<E, Poly<E>>;
+type Params<E> = EVSSParams<<E as PairingEngine>::Fr, Poly<E>, PC<E>>;
+type PublicParams<E> = EVSSPublicParams<<E as PairingEngine>::Fr, Poly<E>, PC<E>>;
+type Polynomial<E> = EVSSPolynomial<<E as PairingEngine>::Fr, Poly<E>, PC<E>>;
+type Commit<E> = EVSSCommit<<E as PairingEngine>::Fr, Poly<E>, PC<E>>;
+type Error<E> = EvssError<<PC<E> as PolynomialCommitment<<E as PairingEngine>::Fr, Poly<E>>>::Error>;
+
+#[derive(Serialize, Deserialize)]
+pub struct EVSSHidingShare<E: PairingEngine> {
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub point: E::Fr,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub value_commit: E::G1Affine,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub witness: E::G1Affine,
+}
+
+impl<E: PairingEngine> std::fmt::Debug for EVSSHidingShare<E> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EVSSHidingShare")
+         .field("point", &self.point)
+         .field("value_commit", &self.value_commit)
+         .finish()
+    }
+
+}
+
+impl<E: PairingEngine> Clone for EVSSHidingShare<E> {
+
+    fn clone(&self) -> Self {
+        EVSSHidingShare {
+            point: self.point,
+            value_commit: self.value_commit,
+            witness: self.witness,
+        }
+    }
+
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EVSSHidingOpening<E: PairingEngine> {
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub value: E::Fr,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub blinding: E::Fr,
+}
+
+impl<E: PairingEngine> Clone for EVSSHidingOpening<E> {
+
+    fn clone(&self) -> Self {
+        EVSSHidingOpening {
+            value: self.value,
+            blinding: self.blinding,
+        }
+    }
+
+}
+
+pub struct EVSSHiding<E: PairingEngine> {
+    _e: PhantomData<E>,
+}
+
+impl<E: PairingEngine> EVSSHiding<E> {
+
+    /// Like `EVSS::setup`, but the keys support hiding commitments with a blinding polynomial
+    /// of higher degree than the shared one.
+    pub fn setup<R: RngCore>(
+        degree: usize,
+        rng: &mut R,
+    ) -> Result<Params<E>, Error<E>> {
+        EVSS::setup_with_hiding_bound(degree, degree, rng)
+    }
+
+    /// Every share reveals the blinding polynomial at its point, so it is blinded with hiding
+    /// bound `pp.degree`: the blinding stays hidden for as many shares as the secret does.
+    pub fn commit<R: RngCore>(
+        pp: &Params<E>,
+        secret: E::Fr,
+        rng: &mut R,
+    ) -> Result<Polynomial<E>, Error<E>> {
+        EVSS::commit_with_hiding_bound(pp, secret, Some(pp.degree), rng)
+    }
+
+    /// Returns the public share together with the opening to send privately to its holder.
+    /// `poly` must come from `EVSSHiding::commit`, otherwise the blinding is zero.
+    pub fn get_share<R: RngCore>(
+        point: E::Fr,
+        params: &Params<E>,
+        poly: &Polynomial<E>,
+        rng: &mut R,
+    ) -> Result<(EVSSHidingShare<E>, EVSSHidingOpening<E>), Error<E>> {
+        let proof = PC::<E>::open(
+            &params.committer_key,
+            once(&label_polynomial_hiding(DEFAULT_LABEL, &poly.polynomial, Some(params.degree))),
+            once(&label_commit::<E::Fr, Poly<E>, PC<E>>(DEFAULT_LABEL, &poly.commit)),
+            &point,
+            E::Fr::one(),
+            once(&poly.rands),
+            Some(rng),
+        )?;
+        let opening = EVSSHidingOpening {
+            value: poly.evaluate(point),
+            blinding: proof.random_v.unwrap_or_else(E::Fr::zero),
+        };
+        let share = EVSSHidingShare {
+            point,
+            value_commit: Self::pedersen(&params.get_public_params(), &opening),
+            witness: proof.w,
+        };
+        Ok((share, opening))
+    }
+
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn check(
+        params: &PublicParams<E>,
+        commit: &Commit<E>,
+        share: &EVSSHidingShare<E>,
+    ) -> bool {
+        let vk = &params.verifier_key.vk;
+        let lhs = E::pairing(
+            commit.commit.comm.0.into_projective() - share.value_commit.into_projective(),
+            vk.h,
+        );
+        let rhs = E::pairing(
+            share.witness,
+            vk.beta_h.into_projective() - vk.h.mul(share.point.into_repr()),
+        );
+        lhs == rhs
+    }
+
+    /// Lets the holder confirm that the private opening matches the public share.
+    #[must_use = "the boolean result indicates whether verification succeeded"]
+    pub fn verify_opening(
+        params: &PublicParams<E>,
+        share: &EVSSHidingShare<E>,
+        opening: &EVSSHidingOpening<E>,
+    ) -> bool {
+        share.value_commit == Self::pedersen(params, opening)
+    }
+
+    /// Interpolates the value commitments at 0, giving a Pedersen commitment to the secret.
+    /// Errors with `InsufficientShares` on no shares and `DuplicatePoint` on a repeated point.
+    pub fn reconstruct(shares: &[EVSSHidingShare<E>]) -> Result<E::G1Affine, Error<E>> {
+        let points: Vec<E::Fr> = shares.iter().map(|sh| sh.point).collect();
+        validate_points(&points, 1)?;
+        Ok(shares
+            .iter()
+            .zip(lagrange_coefficients(&points))
+            .map(|(sh, c)| sh.value_commit.mul(c.into_repr()))
+            .sum::<E::G1Projective>()
+            .into_affine())
+    }
+
+    pub fn pedersen(
+        params: &PublicParams<E>,
+        opening: &EVSSHidingOpening<E>,
+    ) -> E::G1Affine {
+        let vk = &params.verifier_key.vk;
+        let commit = vk.g.mul(opening.value.into_repr()) + vk.gamma_g.mul(opening.blinding.into_repr());
+        commit.into_affine()
+    }
+
+}
@@ -0,0 +1,79 @@
+use ark_ec::PairingEngine;
+use ark_ff::{Field, One, Zero};
+use ark_poly::UVPolynomial;
+use ark_poly_commit::marlin_pc::{Commitment, MarlinKZG10, Randomness};
+use ark_poly_commit::{kzg10, PCCommitment, PCRandomness, PolynomialCommitment};
+use ark_std::ops::Div;
+
+/// A commitment scheme whose commitments and randomness can be combined linearly, so that
+/// operations on committed polynomials carry over to their commitments. Only `MarlinKZG10`
+/// implements it; using the homomorphic APIs with any other scheme fails to compile.
+pub trait HomomorphicPC<F: Field, P: UVPolynomial<F>>: PolynomialCommitment<F, P> {
+
+    fn add_commitments(a: &Self::Commitment, b: &Self::Commitment) -> Self::Commitment;
+
+    fn scale_commitment(c: &Self::Commitment, k: F) -> Self::Commitment;
+
+    fn add_randomness(a: &Self::Randomness, b: &Self::Randomness) -> Self::Randomness;
+
+    fn scale_randomness(r: &Self::Randomness, k: F) -> Self::Randomness;
+
+    /// Combines openings of two polynomials at the same point into an opening of their sum.
+    fn add_proofs(a: &Self::Proof, b: &Self::Proof) -> Self::Proof;
+
+}
+
+impl<E, P> HomomorphicPC<E::Fr, P> for MarlinKZG10<E, P>
+where
+    E: PairingEngine,
+    P: UVPolynomial<E::Fr, Point = E::Fr>,
+    for<'a, 'b> &'a P: Div<&'b P, Output = P>,
+{
+
+    fn add_commitments(a: &Commitment<E>, b: &Commitment<E>) -> Commitment<E> {
+        let mut comm = a.comm;
+        comm += (E::Fr::one(), &b.comm);
+        // A missing shifted commitment stands for zero, as in `Randomness`'s arithmetic.
+        let shifted_comm = match (a.shifted_comm, b.shifted_comm) {
+            (None, None) => None,
+            (sa, sb) => {
+                let mut shifted = sa.unwrap_or_else(kzg10::Commitment::empty);
+                shifted += (E::Fr::one(), &sb.unwrap_or_else(kzg10::Commitment::empty));
+                Some(shifted)
+            }
+        };
+        Commitment { comm, shifted_comm }
+    }
+
+    fn scale_commitment(c: &Commitment<E>, k: E::Fr) -> Commitment<E> {
+        let scale = |comm: &kzg10::Commitment<E>| {
+            let mut scaled = kzg10::Commitment::empty();
+            scaled += (k, comm);
+            scaled
+        };
+        Commitment {
+            comm: scale(&c.comm),
+            shifted_comm: c.shifted_comm.as_ref().map(scale),
+        }
+    }
+
+    fn add_randomness(a: &Randomness<E::Fr, P>, b: &Randomness<E::Fr, P>) -> Randomness<E::Fr, P> {
+        a.clone() + b
+    }
+
+    fn scale_randomness(r: &Randomness<E::Fr, P>, k: E::Fr) -> Randomness<E::Fr, P> {
+        Randomness::empty() + (k, r)
+    }
+
+    fn add_proofs(a: &kzg10::Proof<E>, b: &kzg10::Proof<E>) -> kzg10::Proof<E> {
+        let random_v = match (a.random_v, b.random_v) {
+            (None, None) => None,
+            (va, vb) => Some(va.unwrap_or_else(E::Fr::zero) + vb.unwrap_or_else(E::Fr::zero)),
+        };
+        kzg10::Proof {
+            w: a.w + b.w,
+            random_v,
+        }
+    }
+
+}
@@ -1,7 +1,132 @@
+#[doc(hidden)]
+pub mod __private {
+    pub use ark_ec;
+    pub use ark_ff;
+    pub use ark_poly;
+    pub use ark_poly_commit;
+    pub use ark_serialize;
+    pub use paste;
+}
+
+/// Defines `pub mod $modname` holding the `F`, `Poly`, `PC` and `EVSS*` aliases over
+/// `MarlinKZG10` for a pairing engine, each suffixed with `$suffix`. Any trailing items are
+/// placed in the module as well.
+///
+/// ```
+/// evss::define_evss!(my_curve, ark_bls12_381::Bls12_381, 381);
+/// let _params = my_curve::EVSS381::setup(4, &mut ark_std::test_rng()).unwrap();
+/// ```
+#[macro_export]
+macro_rules! define_evss {
+    ($modname:ident, $engine:ty, $suffix:literal $(, $($item:item)*)?) => {
+        pub mod $modname { $crate::__private::paste::paste! {
+            pub use $crate::__private::ark_ec::PairingEngine;
+            pub use $crate::__private::ark_poly::univariate::DensePolynomial;
+            pub use $crate::__private::ark_poly_commit::marlin_pc::MarlinKZG10;
+
+            pub type [<F $suffix>] = <$engine as PairingEngine>::Fr;
+            pub type [<Poly $suffix>] = DensePolynomial<[<F $suffix>]>;
+            pub type [<PC $suffix>] = MarlinKZG10<$engine, [<Poly $suffix>]>;
+            pub type [<EVSS $suffix>] =
+                $crate::evss::EVSS<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<EVSSParams $suffix>] =
+                $crate::evss::EVSSParams<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<EVSSPublicParams $suffix>] =
+                $crate::evss::EVSSPublicParams<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<CommitterKeyBlob $suffix>] =
+                $crate::evss::CommitterKeyBlob<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<PreparedParams $suffix>] =
+                $crate::evss::PreparedParams<$engine>;
+            pub type [<Dealer $suffix>]<R> =
+                $crate::dealer::Dealer<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>], R>;
+            pub type [<EVSSPolynomial $suffix>] =
+                $crate::evss::EVSSPolynomial<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<EVSSCommit $suffix>] =
+                $crate::evss::EVSSCommit<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<EVSSShare $suffix>] =
+                $crate::evss::EVSSShare<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<KnowledgeProof $suffix>] =
+                $crate::evss::KnowledgeProof<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub use $crate::evss::{CheckOutcome, ParticipantIndex};
+            pub type [<Dealing $suffix>] =
+                $crate::evss::Dealing<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<Complaint $suffix>] =
+                $crate::evss::Complaint<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<PreparedCommit $suffix>] =
+                $crate::evss::PreparedCommit<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<MultiShare $suffix>] =
+                $crate::evss::MultiShare<[<F $suffix>], [<Poly $suffix>], [<PC $suffix>]>;
+            pub type [<EVSSProof $suffix>] =
+                <[<PC $suffix>] as PolynomialCommitment<[<F $suffix>], [<Poly $suffix>]>>::Proof;
+            pub type [<EVSSG1Affine $suffix>] = <$engine as PairingEngine>::G1Affine;
+            pub type [<Feldman $suffix>] =
+                $crate::feldman::Feldman<<$engine as PairingEngine>::G1Projective>;
+            pub type [<FeldmanPolynomial $suffix>] =
+                $crate::feldman::FeldmanPolynomial<<$engine as PairingEngine>::G1Projective>;
+            pub type [<FeldmanCommit $suffix>] =
+                $crate::feldman::FeldmanCommit<<$engine as PairingEngine>::G1Projective>;
+            pub type [<FeldmanShare $suffix>] =
+                $crate::feldman::FeldmanShare<<$engine as PairingEngine>::G1Projective>;
+            pub type [<ShamirSharing $suffix>] = $crate::shamir::ShamirSharing<[<F $suffix>]>;
+            pub type [<ShamirShare $suffix>] = $crate::shamir::ShamirShare<[<F $suffix>]>;
+            pub type [<Reconstructor $suffix>] = $crate::reconstructor::Reconstructor<[<F $suffix>]>;
+            pub type [<EVSSHiding $suffix>] = $crate::hiding::EVSSHiding<$engine>;
+            pub type [<EVSSHidingShare $suffix>] = $crate::hiding::EVSSHidingShare<$engine>;
+            pub type [<EVSSHidingOpening $suffix>] = $crate::hiding::EVSSHidingOpening<$engine>;
+            pub type [<EvssError $suffix>] = $crate::error::EvssError<
+                <[<PC $suffix>] as PolynomialCommitment<[<F $suffix>], [<Poly $suffix>]>>::Error,
+            >;
+
+            pub use $crate::__private::ark_ff::{Field, PrimeField, UniformRand};
+            pub use $crate::__private::ark_poly_commit::PolynomialCommitment;
+            pub use $crate::__private::ark_ff::bytes::{FromBytes, ToBytes};
+            pub use $crate::__private::ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+            $($($item)*)?
+        } }
+    };
+}
+
 pub mod ark_serde;
+pub mod dealer;
+pub mod error;
 pub mod evss;
-pub mod evss381;
+define_evss!(
+    evss381,
+    ark_bls12_381::Bls12_381,
+    381,
+    pub use ark_bls12_381::Bls12_381;
+    #[cfg(test)]
+    mod tests;
+);
+define_evss!(
+    evss377,
+    ark_bls12_377::Bls12_377,
+    377,
+    pub use ark_bls12_377::Bls12_377;
+    #[cfg(test)]
+    mod tests;
+);
+define_evss!(
+    evss254,
+    ark_bn254::Bn254,
+    254,
+    pub use ark_bn254::Bn254;
+    #[cfg(test)]
+    mod tests;
+);
+pub mod evssipa381;
+pub mod feldman;
+pub mod hiding;
+pub mod homomorphic;
+#[cfg(feature = "poseidon")]
+pub mod poseidon;
+pub mod reconstructor;
+pub mod shamir;
+pub mod transcript;
 pub mod biaccumulator;
 pub mod biaccumulator381;
+pub mod helper;
 
-mod helper;
+#[cfg(feature = "wasm")]
+pub mod wasm;
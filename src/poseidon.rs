@@ -0,0 +1,102 @@
+//! A width-3 Poseidon permutation over the scalar field, for `PoseidonTranscript`.
+//!
+//! Parameters: state width 3 (rate 2, capacity 1), S-box `x^alpha` with `alpha` the smallest
+//! of 5, 7, 11, 13, 17 coprime to `p - 1`, 8 full rounds and 57 partial rounds. That is the
+//! round count of the Poseidon paper for width 3, `x^5` and 128-bit security over ~255-bit
+//! fields; larger exponents need fewer rounds, so it is conservative for them. The MDS matrix
+//! is the Cauchy matrix `1 / (i + (3 + j))` and the round constants are Blake2b outputs under
+//! a fixed domain, not the Grain LFSR of the reference implementation, so digests do not
+//! match other Poseidon instances.
+
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+use ark_std::vec::Vec;
+
+use crate::helper::hash_to_field;
+
+pub const WIDTH: usize = 3;
+pub const RATE: usize = 2;
+pub const FULL_ROUNDS: usize = 8;
+pub const PARTIAL_ROUNDS: usize = 57;
+
+const ALPHAS: [u64; 5] = [5, 7, 11, 13, 17];
+const ROUND_CONSTANT_DOMAIN: &[u8] = b"rust-evss/poseidon/round-constants/v1";
+
+#[derive(Clone, Debug)]
+pub struct PoseidonParams<F: PrimeField> {
+    pub alpha: u64,
+    pub mds: [[F; WIDTH]; WIDTH],
+    pub round_constants: Vec<[F; WIDTH]>,
+}
+
+impl<F: PrimeField> PoseidonParams<F> {
+
+    /// Panics if none of the candidate exponents is a permutation of `F`, which does not happen
+    /// for the pairing-friendly scalar fields this crate instantiates.
+    pub fn new() -> Self {
+        let alpha = *ALPHAS
+            .iter()
+            .find(|&&alpha| modulus_minus_one_mod(F::Params::MODULUS, alpha) != 0)
+            .expect("no Poseidon S-box exponent is coprime to p - 1");
+        let mut mds = [[F::zero(); WIDTH]; WIDTH];
+        for (i, row) in mds.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().enumerate() {
+                *entry = F::from((i + WIDTH + j) as u64).inverse().expect("i + 3 + j is non-zero");
+            }
+        }
+        let round_constants = (0..FULL_ROUNDS + PARTIAL_ROUNDS)
+            .map(|round| {
+                let mut constants = [F::zero(); WIDTH];
+                for (i, c) in constants.iter_mut().enumerate() {
+                    *c = hash_to_field(ROUND_CONSTANT_DOMAIN, &((round * WIDTH + i) as u64).to_le_bytes());
+                }
+                constants
+            })
+            .collect();
+        PoseidonParams {
+            alpha,
+            mds,
+            round_constants,
+        }
+    }
+
+    pub fn permute(&self, state: &mut [F; WIDTH]) {
+        let half = FULL_ROUNDS / 2;
+        for (round, constants) in self.round_constants.iter().enumerate() {
+            for (s, c) in state.iter_mut().zip(constants) {
+                *s += c;
+            }
+            if round < half || round >= half + PARTIAL_ROUNDS {
+                for s in state.iter_mut() {
+                    *s = s.pow([self.alpha]);
+                }
+            } else {
+                state[0] = state[0].pow([self.alpha]);
+            }
+            let mut mixed = [F::zero(); WIDTH];
+            for (m, row) in mixed.iter_mut().zip(&self.mds) {
+                for (entry, s) in row.iter().zip(state.iter()) {
+                    *m += *entry * s;
+                }
+            }
+            *state = mixed;
+        }
+    }
+
+}
+
+impl<F: PrimeField> Default for PoseidonParams<F> {
+
+    fn default() -> Self {
+        Self::new()
+    }
+
+}
+
+fn modulus_minus_one_mod<B: BigInteger>(modulus: B, m: u64) -> u64 {
+    let rem = modulus
+        .as_ref()
+        .iter()
+        .rev()
+        .fold(0u128, |acc, &limb| ((acc << 64) | limb as u128) % m as u128) as u64;
+    (rem + m - 1) % m
+}
@@ -0,0 +1,60 @@
+use ark_ff::Field;
+use ark_poly::UVPolynomial;
+use ark_poly_commit::PolynomialCommitment;
+use ark_std::collections::BTreeMap;
+
+use crate::error::EvssError;
+use crate::evss::EVSSShare;
+use crate::helper::lagrange_coefficients;
+
+/// Reconstructs the secret one share at a time. The Lagrange weights for the expected points
+/// are computed up front, so each share is folded in as it arrives and never stored.
+pub struct Reconstructor<F: Field> {
+    weights: BTreeMap<F, Option<F>>,
+    remaining: usize,
+    acc: F,
+}
+
+impl<F: Field> Reconstructor<F> {
+
+    /// `points` must be distinct, otherwise `DuplicatePoint` is returned.
+    pub fn new<E>(points: &[F]) -> Result<Self, EvssError<E>> {
+        let mut weights = BTreeMap::new();
+        for (&point, weight) in points.iter().zip(lagrange_coefficients(points)) {
+            if weights.insert(point, Some(weight)).is_some() {
+                return Err(EvssError::DuplicatePoint);
+            }
+        }
+        Ok(Reconstructor {
+            weights,
+            remaining: points.len(),
+            acc: F::zero(),
+        })
+    }
+
+    /// Folds in a share whose point was passed to `new`. Each point is accepted once.
+    pub fn add_share<P: UVPolynomial<F>, PC: PolynomialCommitment<F, P>>(
+        &mut self,
+        share: &EVSSShare<F, P, PC>,
+    ) -> Result<(), EvssError<PC::Error>> {
+        let weight = self
+            .weights
+            .get_mut(&share.point)
+            .ok_or(EvssError::UnknownPoint)?
+            .take()
+            .ok_or(EvssError::DuplicatePoint)?;
+        self.acc += share.value * weight;
+        self.remaining -= 1;
+        Ok(())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// The secret, which is only correct once `is_complete` holds.
+    pub fn finish(self) -> F {
+        self.acc
+    }
+
+}
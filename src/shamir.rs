@@ -0,0 +1,87 @@
+//! Plain Shamir secret sharing over any field, with no commitments and therefore no way to
+//! verify shares. Use `EVSS` or `Feldman` when the dealer is not trusted.
+
+use ark_ff::Field;
+use ark_poly::univariate::DensePolynomial;
+use ark_poly::{Polynomial, UVPolynomial};
+use ark_std::{iter::once, marker::PhantomData, vec::Vec};
+
+use crate::ark_serde::{canonical_deserialize, canonical_serialize};
+use crate::error::EvssError;
+use crate::helper::{lagrange_coefficients, validate_points};
+
+use rand_core::RngCore;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct ShamirShare<F: Field> {
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub point: F,
+    #[serde(serialize_with = "canonical_serialize")]
+    #[serde(deserialize_with = "canonical_deserialize")]
+    pub value: F,
+}
+
+impl<F: Field> std::fmt::Debug for ShamirShare<F> {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShamirShare")
+         .field("point", &self.point)
+         .field("value", &self.value)
+         .finish()
+    }
+
+}
+
+impl<F: Field> Clone for ShamirShare<F> {
+
+    fn clone(&self) -> Self {
+        ShamirShare {
+            point: self.point,
+            value: self.value,
+        }
+    }
+
+}
+
+pub struct ShamirSharing<F: Field> {
+    _f: PhantomData<F>,
+}
+
+impl<F: Field> ShamirSharing<F> {
+
+    /// `degree` means the same as `EVSSParams::degree`: the polynomial gets `degree`
+    /// coefficients, the secret and `degree - 1` random ones.
+    pub fn share<R: RngCore>(
+        degree: usize,
+        secret: F,
+        points: &[F],
+        rng: &mut R,
+    ) -> Vec<ShamirShare<F>> {
+        let coeffs: Vec<F> = once(secret)
+            .chain((1..degree).map(|_| F::rand(rng)))
+            .collect();
+        let poly = DensePolynomial::from_coefficients_vec(coeffs);
+        points
+            .iter()
+            .map(|&point| ShamirShare {
+                point,
+                value: poly.evaluate(&point),
+            })
+            .collect()
+    }
+
+    /// Interpolates the secret from shares dealt with `degree`. Errors with `InsufficientShares`
+    /// on fewer than `degree` shares and `DuplicatePoint` on a repeated point.
+    pub fn reconstruct<E>(degree: usize, shares: &[ShamirShare<F>]) -> Result<F, EvssError<E>> {
+        let points: Vec<F> = shares.iter().map(|sh| sh.point).collect();
+        validate_points(&points, degree.max(1))?;
+        Ok(shares
+            .iter()
+            .zip(lagrange_coefficients(&points))
+            .map(|(sh, c)| sh.value * c)
+            .sum())
+    }
+
+}
@@ -0,0 +1,195 @@
+//! Fiat-Shamir transcripts for `get_share_with_transcript` and `check_with_transcript`.
+//! `Blake2bTranscript` is the default behind `get_share_fs`/`check_fs`; with the `merlin`
+//! feature a `merlin::Transcript` can be used instead. With the `poseidon` feature,
+//! `PoseidonTranscript` squeezes challenges from the in-crate sponge in `crate::poseidon`.
+
+use ark_ff::Field;
+use ark_serialize::CanonicalSerialize;
+use ark_std::vec::Vec;
+
+use crate::helper::fs_hash;
+
+const FS_DOMAIN: &[u8] = b"rust-evss/fiat-shamir/v1";
+
+/// Absorbs the public parts of a share and squeezes the opening challenge. Dealer and
+/// verifier must start from identical transcripts.
+pub trait EvssTranscript<F: Field> {
+
+    fn append_commitment<C: CanonicalSerialize>(&mut self, commit: &C);
+
+    fn append_point(&mut self, point: &F);
+
+    fn append_value(&mut self, value: &F);
+
+    fn challenge_scalar(&mut self) -> F;
+
+}
+
+/// Hashes everything appended so far with Blake2b, retrying with a counter until the digest
+/// is a valid field element.
+#[derive(Clone, Debug)]
+pub struct Blake2bTranscript {
+    domain: Vec<u8>,
+    buf: Vec<u8>,
+}
+
+impl Blake2bTranscript {
+
+    pub fn new() -> Self {
+        Self::with_domain(FS_DOMAIN)
+    }
+
+    pub fn with_domain(domain: &[u8]) -> Self {
+        Blake2bTranscript {
+            domain: domain.to_vec(),
+            buf: Vec::new(),
+        }
+    }
+
+    fn append<T: CanonicalSerialize>(&mut self, data: &T) {
+        data.serialize(&mut self.buf).expect("serializing into a vector cannot fail");
+    }
+
+}
+
+impl Default for Blake2bTranscript {
+
+    fn default() -> Self {
+        Self::new()
+    }
+
+}
+
+impl<F: Field> EvssTranscript<F> for Blake2bTranscript {
+
+    fn append_commitment<C: CanonicalSerialize>(&mut self, commit: &C) {
+        self.append(commit);
+    }
+
+    fn append_point(&mut self, point: &F) {
+        self.append(point);
+    }
+
+    fn append_value(&mut self, value: &F) {
+        self.append(value);
+    }
+
+    fn challenge_scalar(&mut self) -> F {
+        let challenge: F = fs_hash(&self.domain, &self.buf);
+        self.append(&challenge);
+        challenge
+    }
+
+}
+
+#[cfg(feature = "merlin")]
+impl<F: ark_ff::PrimeField> EvssTranscript<F> for merlin::Transcript {
+
+    fn append_commitment<C: CanonicalSerialize>(&mut self, commit: &C) {
+        let mut buf: Vec<u8> = Vec::new();
+        commit.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        self.append_message(b"commitment", &buf);
+    }
+
+    fn append_point(&mut self, point: &F) {
+        let mut buf: Vec<u8> = Vec::new();
+        point.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        self.append_message(b"point", &buf);
+    }
+
+    fn append_value(&mut self, value: &F) {
+        let mut buf: Vec<u8> = Vec::new();
+        value.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        self.append_message(b"value", &buf);
+    }
+
+    /// Reduces 64 challenge bytes, so the bias is negligible.
+    fn challenge_scalar(&mut self) -> F {
+        let mut bytes = [0u8; 64];
+        self.challenge_bytes(b"challenge", &mut bytes);
+        F::from_le_bytes_mod_order(&bytes)
+    }
+
+}
+
+/// Duplex sponge over `PoseidonParams`. Points and values are absorbed as field elements;
+/// commitments and the domain are absorbed as their length followed by the serialized bytes
+/// packed into field elements.
+#[cfg(feature = "poseidon")]
+#[derive(Clone, Debug)]
+pub struct PoseidonTranscript<F: ark_ff::PrimeField> {
+    params: crate::poseidon::PoseidonParams<F>,
+    state: [F; crate::poseidon::WIDTH],
+    pos: usize,
+}
+
+#[cfg(feature = "poseidon")]
+impl<F: ark_ff::PrimeField> PoseidonTranscript<F> {
+
+    pub fn new() -> Self {
+        Self::with_domain(FS_DOMAIN)
+    }
+
+    pub fn with_domain(domain: &[u8]) -> Self {
+        let mut transcript = PoseidonTranscript {
+            params: crate::poseidon::PoseidonParams::new(),
+            state: [F::zero(); crate::poseidon::WIDTH],
+            pos: 0,
+        };
+        transcript.absorb_bytes(domain);
+        transcript
+    }
+
+    fn absorb(&mut self, element: F) {
+        if self.pos == crate::poseidon::RATE {
+            self.params.permute(&mut self.state);
+            self.pos = 0;
+        }
+        self.state[1 + self.pos] += element;
+        self.pos += 1;
+    }
+
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        let chunk = (<F::Params as ark_ff::FpParameters>::MODULUS_BITS as usize - 1) / 8;
+        self.absorb(F::from(bytes.len() as u64));
+        for c in bytes.chunks(chunk) {
+            self.absorb(F::from_le_bytes_mod_order(c));
+        }
+    }
+
+}
+
+#[cfg(feature = "poseidon")]
+impl<F: ark_ff::PrimeField> Default for PoseidonTranscript<F> {
+
+    fn default() -> Self {
+        Self::new()
+    }
+
+}
+
+#[cfg(feature = "poseidon")]
+impl<F: ark_ff::PrimeField> EvssTranscript<F> for PoseidonTranscript<F> {
+
+    fn append_commitment<C: CanonicalSerialize>(&mut self, commit: &C) {
+        let mut buf: Vec<u8> = Vec::new();
+        commit.serialize(&mut buf).expect("serializing into a vector cannot fail");
+        self.absorb_bytes(&buf);
+    }
+
+    fn append_point(&mut self, point: &F) {
+        self.absorb(*point);
+    }
+
+    fn append_value(&mut self, value: &F) {
+        self.absorb(*value);
+    }
+
+    /// Permutes and reads the first rate element; later appends start a fresh block.
+    fn challenge_scalar(&mut self) -> F {
+        self.params.permute(&mut self.state);
+        self.pos = 0;
+        self.state[1]
+    }
+
+}
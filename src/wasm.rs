@@ -0,0 +1,72 @@
+//! `wasm-bindgen` exports for verifying shares in the browser. Arguments are the serde-JSON
+//! forms of the `*381` types; `setup` and dealing stay native-only.
+
+use ark_serialize::CanonicalSerialize;
+use ark_std::{convert::TryInto, vec::Vec};
+
+use rand_chacha::ChaCha20Rng;
+use rand_core::SeedableRng;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::prelude::*;
+
+use crate::evss381::*;
+
+fn from_json<T: DeserializeOwned>(json: &str) -> Result<T, JsValue> {
+    serde_json::from_str(json).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// `seed` must be 32 bytes, e.g. from `crypto.getRandomValues`.
+#[wasm_bindgen]
+pub fn check(public_params: &str, commit: &str, share: &str, seed: &[u8]) -> Result<bool, JsValue> {
+    let seed: [u8; 32] = seed
+        .try_into()
+        .map_err(|_| JsValue::from_str("seed must be 32 bytes"))?;
+    let public_params: EVSSPublicParams381 = from_json(public_params)?;
+    let commit: EVSSCommit381 = from_json(commit)?;
+    let share: EVSSShare381 = from_json(share)?;
+    EVSS381::check(&public_params, &commit, &share, &mut ChaCha20Rng::from_seed(seed))
+        .map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Takes a JSON array of shares and returns the secret as a hex string.
+#[wasm_bindgen]
+pub fn reconstruct(shares: &str) -> Result<String, JsValue> {
+    let shares: Vec<EVSSShare381> = from_json(shares)?;
+    let secret = EVSS381::reconstruct_ct(&shares).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let mut buf: Vec<u8> = Vec::new();
+    secret
+        .serialize(&mut buf)
+        .map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(hex::encode(&buf))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::evss381::*;
+    use crate::wasm;
+
+    use ark_std::test_rng;
+
+    const DEGREE: usize = 4;
+
+    #[test]
+    fn test_check_and_reconstruct() {
+        let rng = &mut test_rng();
+        let secret = F381::rand(rng);
+        let params = EVSS381::setup(DEGREE, rng).unwrap();
+        let poly = EVSS381::commit(&params, secret, rng).unwrap();
+        let points: Vec<F381> = (1..DEGREE + 2).map(|i| F381::from(i as u32)).collect();
+        let shares = EVSS381::get_shares(&points, &params, &poly, rng).unwrap();
+        let public_params = serde_json::to_string(&params.get_public_params()).unwrap();
+        let commit = serde_json::to_string(&poly.get_commit()).unwrap();
+        for sh in &shares {
+            let share = serde_json::to_string(sh).unwrap();
+            assert!(wasm::check(&public_params, &commit, &share, &[0u8; 32]).unwrap());
+        }
+        let mut bytes = Vec::new();
+        secret.serialize(&mut bytes).unwrap();
+        assert_eq!(hex::encode(&bytes), wasm::reconstruct(&serde_json::to_string(&shares).unwrap()).unwrap());
+    }
+
+}